@@ -2,13 +2,13 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{account, benchmarks, whitelisted_caller};
-use frame_support::traits::Get;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Get, Hooks};
 use frame_system::RawOrigin as SystemOrigin;
 use sp_runtime::traits::Bounded;
 use sp_std::prelude::*;
 
-fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
@@ -17,74 +17,1374 @@ fn generate_string(length: usize) -> Vec<u8> {
     vec![1; length]
 }
 
-fn create_candidacy<T: Config>(caller: T::AccountId, name: Vec<u8>) -> DispatchResult {
-    Pallet::<T>::submit_candidacy(SystemOrigin::Signed(caller).into(), name)
+/// Fund the given account with enough balance for any deposit.
+///
+/// The balance is bounded so that the deposits and bonds moved between funded accounts, as
+/// a slashed bond repatriated to the challenger, can't overflow.
+fn fund<T: Config<I>, I: 'static>(who: &T::AccountId) {
+    let deposits = T::CreationDepositAmount::get()
+        .saturating_add(T::ChallengeBond::get())
+        .saturating_add(T::EvidenceDeposit::get().saturating_mul(T::MaxEvidenceItems::get().into()))
+        .saturating_add(T::LevelDeposit::convert(VerificationLevel::CertifiedLabel))
+        .saturating_add(T::WorkDeposit::get().saturating_mul(T::MaxWorksPerArtist::get().into()))
+        .saturating_add(T::SplitDeposit::get().saturating_mul(T::MaxSplitVersions::get().into()))
+        .saturating_add(
+            T::CollaborationDeposit::get()
+                .saturating_mul(T::MaxPendingCollaborations::get().into()),
+        )
+        .saturating_add(T::FollowDeposit::get())
+        .saturating_add(T::FanTokenDeposit::get())
+        .saturating_add(
+            T::Currency::minimum_balance().saturating_mul(T::MaxVerifiers::get().into()),
+        );
+    T::Currency::make_free_balance_be(who, deposits.saturating_mul(1_000u32.into()));
+}
+
+fn create_candidacy<T: Config<I>, I: 'static>(
+    caller: T::AccountId,
+    name: Vec<u8>,
+) -> DispatchResult {
+    Pallet::<T, I>::submit_candidacy(SystemOrigin::Signed(caller).into(), name)
+}
+
+// Level up the candidate right away, whatever the approval delay
+fn approve_candidacy_of<T: Config<I>, I: 'static>(caller: T::AccountId) -> DispatchResult {
+    Pallet::<T, I>::do_approve_candidacy(&caller, ApprovalSource::Admin)
+}
+
+/// Register artists up to `T::MaxArtists` minus `room`, for the membership changes to be
+/// measured on the largest set.
+fn fill_artist_set<T: Config<I>, I: 'static>(room: u32) -> DispatchResult {
+    let size = Artists::<T, I>::count();
+    for i in size..T::MaxArtists::get().saturating_sub(room) {
+        let member: T::AccountId = account("member", i, 0);
+        let profile = ArtistProfile {
+            name: BoundedVec::truncate_from(generate_string(1)),
+            metadata: Default::default(),
+        };
+        Pallet::<T, I>::do_force_register_artist(&member, profile, true)?;
+    }
+    Ok(())
+}
+
+/// Fill the set of the current candidates up to `T::MaxCandidates` minus `room` members
+/// through actual candidacies, for the candidate set to be measured at its largest.
+fn fill_candidate_set<T: Config<I>, I: 'static>(room: u32) -> DispatchResult {
+    let size = Pallet::<T, I>::current_candidates().len() as u32;
+    for i in size..T::MaxCandidates::get().saturating_sub(room) {
+        let candidate: T::AccountId = account("applicant", i, 0);
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(candidate, generate_string(1))?;
+    }
+    Ok(())
+}
+
+/// Fund the given accounts and make them follow an artist.
+fn follow_of<T: Config<I>, I: 'static>(artist_id: ArtistId, count: u32) -> DispatchResult {
+    for i in 0..count {
+        let follower: T::AccountId = account("follower", i, 0);
+        fund::<T, I>(&follower);
+        Pallet::<T, I>::follow_artist(SystemOrigin::Signed(follower).into(), artist_id)?;
+    }
+    Ok(())
+}
+
+/// Fund an account and level it up to be an artist with the longest name.
+fn create_artist<T: Config<I>, I: 'static>(artist: &T::AccountId) -> DispatchResult {
+    fund::<T, I>(artist);
+    create_candidacy::<T, I>(
+        artist.clone(),
+        generate_string(T::NameMaxLength::get() as usize),
+    )?;
+    approve_candidacy_of::<T, I>(artist.clone())
+}
+
+fn add_verifier_of<T: Config<I>, I: 'static>(
+    verifier: T::AccountId,
+    fee: BalanceOf<T, I>,
+) -> DispatchResult {
+    Pallet::<T, I>::add_verifier(SystemOrigin::Root.into(), verifier, fee)
 }
 
-fn approve_candidacy_of<T: Config>(caller: T::AccountId) -> DispatchResult {
-    Pallet::<T>::approve_candidacy(SystemOrigin::Root.into(), caller)
+fn request_attestation_of<T: Config<I>, I: 'static>(
+    candidate: T::AccountId,
+    verifier: T::AccountId,
+) -> DispatchResult {
+    Pallet::<T, I>::request_attestation(
+        SystemOrigin::Signed(candidate).into(),
+        verifier,
+        BalanceOf::<T, I>::max_value(),
+    )
 }
 
-benchmarks! {
-    where_clause { where T: Config }
+/// Check that the approval of a candidate was scheduled after `T::ApprovalDelay` blocks,
+/// or enacted right away when there is no delay.
+fn assert_approved<T: Config<I>, I: 'static>(candidate: &T::AccountId, source: ApprovalSource) {
+    let delay = T::ApprovalDelay::get();
+    if delay.is_zero() {
+        assert!(Pallet::<T, I>::is_registered_artist(candidate));
+        assert!(!Pallet::<T, I>::is_candidate(candidate));
+    } else {
+        let enact_at = frame_system::Pallet::<T>::block_number() + delay;
+        assert_eq!(
+            PendingApprovals::<T, I>::get(candidate),
+            Some((enact_at, source))
+        );
+        assert!(ApprovalQueue::<T, I>::get(enact_at).contains(candidate));
+        assert!(!Pallet::<T, I>::is_registered_artist(candidate));
+    }
+}
 
-    submit_candidacy {
-        let n in 1..T::NameMaxLength::get();
+/// Schedule the approval of a candidate at the given block, whatever the configured delay.
+fn schedule_approval_of<T: Config<I>, I: 'static>(
+    candidate: &T::AccountId,
+    enact_at: BlockNumberFor<T>,
+) -> DispatchResult {
+    ApprovalQueue::<T, I>::try_append(enact_at, candidate)
+        .map_err(|_| Error::<T, I>::TooManyScheduledApprovals)?;
+    PendingApprovals::<T, I>::insert(candidate, (enact_at, ApprovalSource::Admin));
+    Ok(())
+}
+
+/// Attach the maximum of evidence, attestation requests and endorsements to a
+/// candidacy, for the worst case of its removal.
+fn fill_candidacy<T: Config<I>, I: 'static>(candidate: &T::AccountId) -> DispatchResult {
+    let evidence = Evidence::Link(
+        generate_string(T::EvidenceMaxLength::get() as usize)
+            .try_into()
+            .unwrap(),
+    );
+    for _ in 0..T::MaxEvidenceItems::get() {
+        Pallet::<T, I>::add_candidacy_evidence(
+            SystemOrigin::Signed(candidate.clone()).into(),
+            evidence.clone(),
+        )?;
+    }
+
+    for i in 0..T::MaxVerifiers::get() {
+        let verifier: T::AccountId = account("verifier", i, 0);
+        add_verifier_of::<T, I>(verifier.clone(), T::Currency::minimum_balance())?;
+        request_attestation_of::<T, I>(candidate.clone(), verifier)?;
+    }
+
+    let endorsements = (0..T::MaxEndorsements::get())
+        .map(|i| account("endorser", i, 0))
+        .collect::<Vec<T::AccountId>>();
+    Endorsements::<T, I>::insert(candidate, BoundedVec::truncate_from(endorsements));
+    Ok(())
+}
+
+/// A work with the longest title, whose ISRC is derived from the given index.
+fn work_of<T: Config<I>, I: 'static>(index: u32) -> WorkOf<T, I> {
+    let mut isrc: Isrc = *b"FRAFE2400000";
+    for (position, digit) in isrc.iter_mut().rev().take(5).enumerate() {
+        *digit = b'0' + (index / 10u32.pow(position as u32) % 10) as u8;
+    }
+    Work {
+        title: BoundedVec::truncate_from(generate_string(T::TitleMaxLength::get() as usize)),
+        isrc,
+        iswc: Some(*b"T0345246801"),
+        content_hash: T::Hashing::hash_of(&index),
+        released_at: frame_system::Pallet::<T>::block_number(),
+    }
+}
+
+/// Register the given number of works in the catalog of an artist.
+fn fill_catalog<T: Config<I>, I: 'static>(artist: &T::AccountId, count: u32) -> DispatchResult {
+    for index in 0..count {
+        Pallet::<T, I>::register_work(
+            SystemOrigin::Signed(artist.clone()).into(),
+            work_of::<T, I>(index),
+        )?;
+    }
+    Ok(())
+}
+
+/// Fill the pending proposals of the given artists, up to one short of the limit.
+fn fill_pending_collaborations<T: Config<I>, I: 'static>(artists: &[&T::AccountId]) {
+    let pending = (0..T::MaxPendingCollaborations::get().saturating_sub(1))
+        .map(|id| id + 1)
+        .collect::<Vec<CollaborationId>>();
+    for artist in artists {
+        PendingCollaborations::<T, I>::insert(*artist, BoundedVec::truncate_from(pending.clone()));
+    }
+}
+
+/// A royalty split between the given number of payees, summing to 100%.
+fn shares_of<T: Config<I>, I: 'static>(payees: u32) -> SharesOf<T, I> {
+    let total = Perbill::one().deconstruct();
+    let share = total / payees;
+    let shares = (0..payees)
+        .map(|i| {
+            let parts = if i + 1 == payees {
+                total - share * (payees - 1)
+            } else {
+                share
+            };
+            (account("payee", i, 0), Perbill::from_parts(parts))
+        })
+        .collect::<Vec<_>>();
+    BoundedVec::truncate_from(shares)
+}
+
+#[instance_benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn submit_candidacy(n: Linear<1, { T::NameMaxLength::get() }>) -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
-        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
-    }: _(SystemOrigin::Signed(caller.clone()), generate_string(n.try_into().unwrap()))
-    verify {
-        assert_last_event::<T>(Event::CandidateAdded { 0: caller }.into());
+        fund::<T, I>(&caller);
+        fill_candidate_set::<T, I>(1)?;
+        let name = generate_string(n as usize);
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(caller.clone()), name.clone());
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CandidateAdded {
+                candidate: caller,
+                name: name.try_into().unwrap(),
+                deposit: T::CreationDepositAmount::get(),
+                created_at: frame_system::Pallet::<T>::block_number(),
+            }
+            .into(),
+        );
+        Ok(())
     }
 
-    withdraw_candidacy {
+    // Worst case: a sponsored candidacy with a scheduled approval and full attachments
+    #[benchmark]
+    fn withdraw_candidacy() -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
-        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
-        create_candidacy::<T>(caller.clone(), generate_string(T::NameMaxLength::get() as usize))?;
-    }: _(SystemOrigin::Signed(caller.clone()))
-    verify {
-        assert_last_event::<T>(Event::CandidateWithdrew { 0: caller }.into());
+        let sponsor: T::AccountId = account("sponsor", 0, 0);
+        fund::<T, I>(&caller);
+        fund::<T, I>(&sponsor);
+        Pallet::<T, I>::sponsor_candidacy(
+            SystemOrigin::Signed(sponsor.clone()).into(),
+            caller.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        fill_candidacy::<T, I>(&caller)?;
+        schedule_approval_of::<T, I>(
+            &caller,
+            frame_system::Pallet::<T>::block_number() + 10u32.into(),
+        )?;
+        fill_candidate_set::<T, I>(0)?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(caller.clone()));
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CandidateWithdrew {
+                candidate: caller,
+                depositor: sponsor,
+                deposit: T::CreationDepositAmount::get(),
+            }
+            .into(),
+        );
+        Ok(())
     }
 
-    approve_candidacy {
-        let n in 1..T::NameMaxLength::get();
-        let admin: T::AccountId = whitelisted_caller();
-        let candidate: T::AccountId = whitelisted_caller();
-        T::Currency::make_free_balance_be(&candidate, BalanceOf::<T>::max_value());
-        create_candidacy::<T>(candidate.clone(), generate_string(n.try_into().unwrap()))?;
-    }: _(SystemOrigin::Root, candidate.clone())
-    verify {
-        assert_last_event::<T>(Event::CandidateApproved { 0: candidate }.into());
+    #[benchmark]
+    fn approve_candidacy(n: Linear<1, { T::NameMaxLength::get() }>) -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = account("candidate", 0, 0);
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(candidate.clone(), generate_string(n as usize))?;
+        fill_candidacy::<T, I>(&candidate)?;
+        fill_candidate_set::<T, I>(0)?;
+        fill_artist_set::<T, I>(1)?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, candidate.clone());
+
+        // Measures the scheduling, the enactment being measured by `enact_approvals`.
+        // Without delay, the call enacts the approval itself and is measured as such.
+        assert_approved::<T, I>(&candidate, ApprovalSource::Admin);
+        Ok(())
     }
 
-    call_as_artist {
+    // Worst case: the inner call is an action of this pallet, building up the reputation.
+    // Its own weight is added on top of the overhead.
+    #[benchmark]
+    fn call_as_artist() -> Result<(), BenchmarkError> {
         let artist: T::AccountId = whitelisted_caller();
-        let call: <T as Config>::Call = frame_system::Call::<T>::remark { remark: vec![] }.into();
-        T::Currency::make_free_balance_be(&artist, BalanceOf::<T>::max_value());
-        create_candidacy::<T>(artist.clone(), generate_string(T::NameMaxLength::get() as usize))?;
-        approve_candidacy_of::<T>(artist.clone())?;
-    }: _(SystemOrigin::Signed(artist.clone()), Box::new(call.clone()))
-    verify {
+        create_artist::<T, I>(&artist)?;
+        Pallet::<T, I>::create_split(
+            SystemOrigin::Signed(artist.clone()).into(),
+            shares_of::<T, I>(1),
+        )?;
+        let call: <T as Config<I>>::Call = Call::<T, I>::lock_split { id: 0 }.into();
         let dispatch_hash = T::Hashing::hash_of(&call);
-        // Note that execution fails due to mis-matched origin
-        assert_last_event::<T>(
-            Event::ArtistExecuted { dispatch_hash, result: Ok(()) }.into()
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist), Box::new(call));
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::ArtistExecuted {
+                dispatch_hash,
+                result: Ok(()),
+            }
+            .into(),
         );
+        Ok(())
     }
 
-    call_as_candidate {
-        let candidate: T::AccountId = account("alice", 0, 0);
-        let call: <T as Config>::Call = frame_system::Call::<T>::remark { remark: vec![] }.into();
-        T::Currency::make_free_balance_be(&candidate, BalanceOf::<T>::max_value());
-        create_candidacy::<T>(candidate.clone(), generate_string(T::NameMaxLength::get() as usize))?;
-    }: _(SystemOrigin::Signed(candidate.clone()), Box::new(call.clone()))
-    verify {
+    // The inner remark does nothing, its own weight is added on top of the overhead.
+    // The candidate already used its call budget of the era but for one call.
+    #[benchmark]
+    fn call_as_candidate() -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = whitelisted_caller();
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        CandidateCallUsage::<T, I>::insert(
+            &candidate,
+            CallUsage {
+                era_start: frame_system::Pallet::<T>::block_number(),
+                calls: T::MaxCandidateCalls::get().saturating_sub(1),
+                weight: Weight::zero(),
+            },
+        );
+        let call: <T as Config<I>>::Call =
+            frame_system::Call::<T>::remark { remark: vec![] }.into();
         let dispatch_hash = T::Hashing::hash_of(&call);
-        // Note that execution fails due to mis-matched origin
-        assert_last_event::<T>(
-            Event::CandidateExecuted { dispatch_hash, result: Ok(()) }.into()
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(candidate), Box::new(call));
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CandidateExecuted {
+                dispatch_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_verifier() -> Result<(), BenchmarkError> {
+        // Fill the verifier set, leaving room for the benchmarked one
+        for i in 1..T::MaxVerifiers::get() {
+            add_verifier_of::<T, I>(account("verifier", i, 0), T::Currency::minimum_balance())?;
+        }
+        let verifier: T::AccountId = account("verifier", 0, 0);
+        let fee = T::Currency::minimum_balance();
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, verifier.clone(), fee);
+
+        assert_last_event::<T, I>(Event::<T, I>::VerifierAdded { verifier, fee }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_verifier() -> Result<(), BenchmarkError> {
+        for i in 0..T::MaxVerifiers::get() {
+            add_verifier_of::<T, I>(account("verifier", i, 0), T::Currency::minimum_balance())?;
+        }
+        let verifier: T::AccountId = account("verifier", 0, 0);
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, verifier.clone());
+
+        assert_last_event::<T, I>(Event::<T, I>::VerifierRemoved { verifier }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_verifier_fee() -> Result<(), BenchmarkError> {
+        let verifier: T::AccountId = whitelisted_caller();
+        let fee = T::Currency::minimum_balance();
+        add_verifier_of::<T, I>(verifier.clone(), fee)?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(verifier.clone()), fee + fee);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::VerifierFeeSet {
+                verifier,
+                fee: fee + fee,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_attestation() -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = whitelisted_caller();
+        let verifier: T::AccountId = account("verifier", 0, 0);
+        let fee = T::Currency::minimum_balance();
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        add_verifier_of::<T, I>(verifier.clone(), fee)?;
+
+        #[extrinsic_call]
+        _(
+            SystemOrigin::Signed(candidate.clone()),
+            verifier.clone(),
+            fee,
+        );
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::AttestationRequested {
+                candidate,
+                verifier,
+                fee,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_attestation_request() -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = whitelisted_caller();
+        let verifier: T::AccountId = account("verifier", 0, 0);
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        add_verifier_of::<T, I>(verifier.clone(), T::Currency::minimum_balance())?;
+        request_attestation_of::<T, I>(candidate.clone(), verifier.clone())?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(candidate.clone()), verifier.clone());
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::AttestationRequestCancelled {
+                candidate,
+                verifier,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // Worst case: the benchmarked verdict reaches the quorum and approves the candidate
+    #[benchmark]
+    fn attest_candidacy(v: Linear<1, { T::MaxVerifiers::get() }>) -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = account("candidate", 0, 0);
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+
+        // Fill the previous verdicts, without reaching the quorum before the benchmarked call
+        for i in 0..v {
+            let verifier: T::AccountId = account("verifier", i, 0);
+            add_verifier_of::<T, I>(verifier.clone(), T::Currency::minimum_balance())?;
+            request_attestation_of::<T, I>(candidate.clone(), verifier.clone())?;
+            if i + 1 < v {
+                let verdict = if i + 1 < T::AttestationQuorum::get() {
+                    Verdict::Approved
+                } else {
+                    Verdict::Rejected
+                };
+                Pallet::<T, I>::attest_candidacy(
+                    SystemOrigin::Signed(verifier).into(),
+                    candidate.clone(),
+                    verdict,
+                )?;
+            }
+        }
+        let verifier: T::AccountId = account("verifier", v - 1, 0);
+
+        #[extrinsic_call]
+        _(
+            SystemOrigin::Signed(verifier.clone()),
+            candidate.clone(),
+            Verdict::Approved,
+        );
+
+        assert!(!AttestationRequests::<T, I>::contains_key(
+            &candidate, &verifier
+        ));
+        Ok(())
+    }
+
+    // Worst case: the highest level deposit is held on top of the creation deposit
+    #[benchmark]
+    fn set_verification_level() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&artist)?;
+
+        #[extrinsic_call]
+        _(
+            SystemOrigin::Root,
+            artist.clone(),
+            VerificationLevel::CertifiedLabel,
+        );
+
+        assert_eq!(
+            Artists::<T, I>::get(&artist).map(|record| record.level),
+            Some(VerificationLevel::CertifiedLabel)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_candidacy_evidence() -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = whitelisted_caller();
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        let evidence = Evidence::Link(
+            generate_string(T::EvidenceMaxLength::get() as usize)
+                .try_into()
+                .unwrap(),
+        );
+        // Fill the evidence list, leaving room for the benchmarked one
+        for _ in 1..T::MaxEvidenceItems::get() {
+            Pallet::<T, I>::add_candidacy_evidence(
+                SystemOrigin::Signed(candidate.clone()).into(),
+                evidence.clone(),
+            )?;
+        }
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(candidate.clone()), evidence.clone());
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CandidacyEvidenceAdded {
+                candidate,
+                evidence,
+                deposit: T::EvidenceDeposit::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // Worst case: a sponsored candidacy with a scheduled approval and full attachments
+    #[benchmark]
+    fn reject_candidacy() -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = account("candidate", 0, 0);
+        let sponsor: T::AccountId = account("sponsor", 0, 0);
+        fund::<T, I>(&candidate);
+        fund::<T, I>(&sponsor);
+        Pallet::<T, I>::sponsor_candidacy(
+            SystemOrigin::Signed(sponsor.clone()).into(),
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        fill_candidacy::<T, I>(&candidate)?;
+        schedule_approval_of::<T, I>(
+            &candidate,
+            frame_system::Pallet::<T>::block_number() + 10u32.into(),
+        )?;
+        fill_candidate_set::<T, I>(0)?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, candidate.clone());
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CandidateRejected {
+                candidate,
+                depositor: sponsor,
+                deposit: T::CreationDepositAmount::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn challenge_artist() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        let challenger: T::AccountId = whitelisted_caller();
+        let evidence_hash = T::Hashing::hash_of(&artist);
+        fund::<T, I>(&challenger);
+        create_artist::<T, I>(&artist)?;
+
+        #[extrinsic_call]
+        _(
+            SystemOrigin::Signed(challenger.clone()),
+            artist.clone(),
+            evidence_hash,
+        );
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::ArtistChallenged {
+                artist,
+                challenger,
+                evidence_hash,
+                bond: T::ChallengeBond::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // Worst case: upholding the challenge of a sponsored artist with a full catalog, leaving
+    // the largest set
+    #[benchmark]
+    fn resolve_dispute() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        let sponsor: T::AccountId = account("sponsor", 0, 0);
+        let challenger: T::AccountId = account("challenger", 0, 0);
+        fund::<T, I>(&artist);
+        fund::<T, I>(&sponsor);
+        fund::<T, I>(&challenger);
+        Pallet::<T, I>::sponsor_candidacy(
+            SystemOrigin::Signed(sponsor).into(),
+            artist.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        approve_candidacy_of::<T, I>(artist.clone())?;
+        fill_catalog::<T, I>(&artist, T::MaxWorksPerArtist::get())?;
+        fill_artist_set::<T, I>(0)?;
+        Pallet::<T, I>::challenge_artist(
+            SystemOrigin::Signed(challenger).into(),
+            artist.clone(),
+            T::Hashing::hash_of(&artist),
+        )?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, artist.clone(), true);
+
+        assert!(!Pallet::<T, I>::is_registered_artist(&artist));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn close_expired_dispute() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        let challenger: T::AccountId = whitelisted_caller();
+        fund::<T, I>(&challenger);
+        create_artist::<T, I>(&artist)?;
+        Pallet::<T, I>::challenge_artist(
+            SystemOrigin::Signed(challenger.clone()).into(),
+            artist.clone(),
+            T::Hashing::hash_of(&artist),
+        )?;
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::DisputeTimeout::get(),
+        );
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(challenger.clone()), artist.clone());
+
+        assert_last_event::<T, I>(Event::<T, I>::DisputeExpired { artist, challenger }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn endorse_candidate(
+        e: Linear<1, { T::MaxEndorsements::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = account("candidate", 0, 0);
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+
+        // Fill the previous endorsements
+        for i in 0..e {
+            let artist: T::AccountId = account("artist", i, 0);
+            create_artist::<T, I>(&artist)?;
+            if i + 1 < e {
+                Endorsements::<T, I>::mutate(&candidate, |endorsements| {
+                    endorsements
+                        .try_push(artist)
+                        .expect("bounded by MaxEndorsements");
+                });
+            }
+        }
+        let artist: T::AccountId = account("artist", e - 1, 0);
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist), candidate.clone());
+
+        // The last endorsement may reach the threshold, approving the candidate
+        if T::EndorsementThreshold::get().map_or(false, |threshold| e >= threshold) {
+            assert_approved::<T, I>(&candidate, ApprovalSource::Endorsements);
+        } else {
+            assert_eq!(
+                Endorsements::<T, I>::decode_len(&candidate),
+                Some(e as usize)
+            );
+        }
+        Ok(())
+    }
+
+    // Worst case: replacing an existing suspension, other suspensions ending at both
+    // the previous and the new blocks
+    #[benchmark]
+    fn suspend_artist() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&artist)?;
+        fill_artist_set::<T, I>(0)?;
+        let now = frame_system::Pallet::<T>::block_number();
+        let previous = now + 10u32.into();
+        let until = now + 20u32.into();
+        Pallet::<T, I>::suspend_artist(
+            SystemOrigin::Root.into(),
+            artist.clone(),
+            previous,
+            SuspensionReason::Other,
+        )?;
+        for i in 1..T::MaxSuspensionsPerBlock::get() {
+            let other: T::AccountId = account("other", i, 0);
+            SuspensionExpiries::<T, I>::try_append(previous, &other)
+                .map_err(|_| Error::<T, I>::TooManySuspensions)?;
+            SuspensionExpiries::<T, I>::try_append(until, &other)
+                .map_err(|_| Error::<T, I>::TooManySuspensions)?;
+        }
+
+        #[extrinsic_call]
+        _(
+            SystemOrigin::Root,
+            artist.clone(),
+            until,
+            SuspensionReason::CompromisedKey,
+        );
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::ArtistSuspended {
+                artist,
+                until,
+                reason: SuspensionReason::CompromisedKey,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unsuspend_artist() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&artist)?;
+        let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        Pallet::<T, I>::suspend_artist(
+            SystemOrigin::Root.into(),
+            artist.clone(),
+            until,
+            SuspensionReason::Other,
+        )?;
+        for i in 1..T::MaxSuspensionsPerBlock::get() {
+            let other: T::AccountId = account("other", i, 0);
+            SuspensionExpiries::<T, I>::try_append(until, &other)
+                .map_err(|_| Error::<T, I>::TooManySuspensions)?;
+        }
+        // The suspended artist still counts, the set is full once it is back
+        fill_artist_set::<T, I>(0)?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, artist.clone());
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::ArtistUnsuspended {
+                artist,
+                until,
+                early: true,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn lift_suspensions(
+        s: Linear<0, { T::MaxSuspensionsPerBlock::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        for i in 0..s {
+            let artist: T::AccountId = account("artist", i, 0);
+            create_artist::<T, I>(&artist)?;
+            Pallet::<T, I>::suspend_artist(
+                SystemOrigin::Root.into(),
+                artist,
+                until,
+                SuspensionReason::Other,
+            )?;
+        }
+
+        #[block]
+        {
+            Pallet::<T, I>::on_initialize(until);
+        }
+
+        assert!(SuspensionExpiries::<T, I>::get(until).is_empty());
+        Ok(())
+    }
+
+    // Worst case: other approvals are scheduled at the same block
+    #[benchmark]
+    fn veto_approval() -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = account("candidate", 0, 0);
+        fund::<T, I>(&candidate);
+        create_candidacy::<T, I>(
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        let enact_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        for i in 1..T::MaxApprovalsPerBlock::get() {
+            schedule_approval_of::<T, I>(&account("other", i, 0), enact_at)?;
+        }
+        schedule_approval_of::<T, I>(&candidate, enact_at)?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, candidate.clone());
+
+        assert_last_event::<T, I>(Event::<T, I>::ApprovalVetoed { candidate }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn enact_approvals(
+        a: Linear<0, { T::MaxApprovalsPerBlock::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let enact_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        for i in 0..a {
+            let candidate: T::AccountId = account("candidate", i, 0);
+            fund::<T, I>(&candidate);
+            create_candidacy::<T, I>(
+                candidate.clone(),
+                generate_string(T::NameMaxLength::get() as usize),
+            )?;
+            schedule_approval_of::<T, I>(&candidate, enact_at)?;
+        }
+        fill_candidate_set::<T, I>(0)?;
+        // The enacted candidates fill the artist set
+        fill_artist_set::<T, I>(a)?;
+
+        #[block]
+        {
+            Pallet::<T, I>::on_initialize(enact_at);
+        }
+
+        assert!(ApprovalQueue::<T, I>::get(enact_at).is_empty());
+        for i in 0..a {
+            let candidate: T::AccountId = account("candidate", i, 0);
+            assert!(Pallet::<T, I>::is_registered_artist(&candidate));
+            assert!(!PendingApprovals::<T, I>::contains_key(&candidate));
+        }
+        Ok(())
+    }
+
+    #[benchmark]
+    fn sponsor_candidacy(n: Linear<1, { T::NameMaxLength::get() }>) -> Result<(), BenchmarkError> {
+        let sponsor: T::AccountId = whitelisted_caller();
+        let candidate: T::AccountId = account("candidate", 0, 0);
+        fund::<T, I>(&sponsor);
+        fill_candidate_set::<T, I>(1)?;
+
+        #[extrinsic_call]
+        _(
+            SystemOrigin::Signed(sponsor.clone()),
+            candidate.clone(),
+            generate_string(n as usize),
+        );
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CandidateSponsored {
+                candidate,
+                sponsor,
+                deposit: T::CreationDepositAmount::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn take_over_deposit() -> Result<(), BenchmarkError> {
+        let sponsor: T::AccountId = account("sponsor", 0, 0);
+        let candidate: T::AccountId = whitelisted_caller();
+        fund::<T, I>(&sponsor);
+        fund::<T, I>(&candidate);
+        Pallet::<T, I>::sponsor_candidacy(
+            SystemOrigin::Signed(sponsor.clone()).into(),
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(candidate.clone()));
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::SponsorshipEnded {
+                who: candidate,
+                sponsor,
+                deposit: T::CreationDepositAmount::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // Worst case: registering a sponsored candidate with full attachments, holding the deposit
+    #[benchmark]
+    fn force_register_artist() -> Result<(), BenchmarkError> {
+        let candidate: T::AccountId = account("candidate", 0, 0);
+        let sponsor: T::AccountId = account("sponsor", 0, 0);
+        fund::<T, I>(&candidate);
+        fund::<T, I>(&sponsor);
+        Pallet::<T, I>::sponsor_candidacy(
+            SystemOrigin::Signed(sponsor).into(),
+            candidate.clone(),
+            generate_string(T::NameMaxLength::get() as usize),
+        )?;
+        fill_candidacy::<T, I>(&candidate)?;
+        schedule_approval_of::<T, I>(
+            &candidate,
+            frame_system::Pallet::<T>::block_number() + 10u32.into(),
+        )?;
+        fill_candidate_set::<T, I>(0)?;
+        fill_artist_set::<T, I>(1)?;
+        let profile = ArtistProfile {
+            name: BoundedVec::truncate_from(generate_string(T::NameMaxLength::get() as usize)),
+            metadata: BoundedVec::truncate_from(generate_string(
+                T::MetadataMaxLength::get() as usize
+            )),
+        };
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, candidate.clone(), profile, false);
+
+        assert!(Pallet::<T, I>::is_artist(&candidate));
+        assert!(!Pallet::<T, I>::is_candidate(&candidate));
+        Ok(())
+    }
+
+    // Worst case: both artists already have the longest lists of pending proposals
+    #[benchmark]
+    fn propose_collaboration() -> Result<(), BenchmarkError> {
+        let proposer: T::AccountId = whitelisted_caller();
+        let counterparty: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&proposer)?;
+        create_artist::<T, I>(&counterparty)?;
+        fill_pending_collaborations::<T, I>(&[&proposer, &counterparty]);
+        let work_hash = T::Hashing::hash_of(&proposer);
+        let metadata =
+            BoundedVec::truncate_from(generate_string(T::MetadataMaxLength::get() as usize));
+
+        #[extrinsic_call]
+        _(
+            SystemOrigin::Signed(proposer.clone()),
+            counterparty.clone(),
+            work_hash,
+            metadata,
+        );
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CollaborationProposed {
+                id: 0,
+                proposer,
+                counterparty,
+                work_hash,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // Worst case: both artists already have the longest lists of collaborations
+    #[benchmark]
+    fn accept_collaboration() -> Result<(), BenchmarkError> {
+        let proposer: T::AccountId = account("artist", 0, 0);
+        let counterparty: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&proposer)?;
+        create_artist::<T, I>(&counterparty)?;
+        let previous = (0..T::MaxCollaborations::get().saturating_sub(1))
+            .map(|id| id + 1)
+            .collect::<Vec<CollaborationId>>();
+        ArtistCollaborations::<T, I>::insert(
+            &proposer,
+            BoundedVec::truncate_from(previous.clone()),
+        );
+        ArtistCollaborations::<T, I>::insert(&counterparty, BoundedVec::truncate_from(previous));
+        fill_pending_collaborations::<T, I>(&[&proposer, &counterparty]);
+        Pallet::<T, I>::propose_collaboration(
+            SystemOrigin::Signed(proposer.clone()).into(),
+            counterparty.clone(),
+            T::Hashing::hash_of(&proposer),
+            BoundedVec::truncate_from(generate_string(T::MetadataMaxLength::get() as usize)),
+        )?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(counterparty.clone()), 0);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CollaborationConfirmed {
+                id: 0,
+                proposer,
+                counterparty,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_collaboration() -> Result<(), BenchmarkError> {
+        let proposer: T::AccountId = whitelisted_caller();
+        let counterparty: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&proposer)?;
+        create_artist::<T, I>(&counterparty)?;
+        fill_pending_collaborations::<T, I>(&[&proposer, &counterparty]);
+        Pallet::<T, I>::propose_collaboration(
+            SystemOrigin::Signed(proposer.clone()).into(),
+            counterparty,
+            T::Hashing::hash_of(&proposer),
+            BoundedVec::truncate_from(generate_string(T::MetadataMaxLength::get() as usize)),
+        )?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(proposer.clone()), 0);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CollaborationCancelled {
+                id: 0,
+                cancelled_by: proposer,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_work() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        let last = T::MaxWorksPerArtist::get().saturating_sub(1);
+        fill_catalog::<T, I>(&artist, last)?;
+        let work = work_of::<T, I>(last);
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist.clone()), work.clone());
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::WorkRegistered {
+                artist,
+                isrc: work.isrc,
+                content_hash: work.content_hash,
+                deposit: T::WorkDeposit::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // Worst case: the updated work is the last of a full catalog
+    #[benchmark]
+    fn update_work() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        fill_catalog::<T, I>(&artist, T::MaxWorksPerArtist::get())?;
+        let mut work = work_of::<T, I>(T::MaxWorksPerArtist::get().saturating_sub(1));
+        work.iswc = None;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist.clone()), work.clone());
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::WorkUpdated {
+                artist,
+                isrc: work.isrc,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // Worst case: the retired work is the last of a full catalog
+    #[benchmark]
+    fn retire_work() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        fill_catalog::<T, I>(&artist, T::MaxWorksPerArtist::get())?;
+        let isrc = work_of::<T, I>(T::MaxWorksPerArtist::get().saturating_sub(1)).isrc;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist.clone()), isrc);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::WorkRetired {
+                artist,
+                isrc,
+                deposit: T::WorkDeposit::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_split(p: Linear<1, { T::MaxSplitPayees::get() }>) -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        // Worst case: the artist owns all the splits but one
+        let previous = (0..T::MaxSplitsPerArtist::get().saturating_sub(1))
+            .map(|id| id + 1)
+            .collect::<Vec<SplitId>>();
+        ArtistSplits::<T, I>::insert(&artist, BoundedVec::truncate_from(previous));
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist.clone()), shares_of::<T, I>(p));
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::SplitCreated {
+                id: 0,
+                owner: artist,
+                deposit: T::SplitDeposit::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn update_split(p: Linear<1, { T::MaxSplitPayees::get() }>) -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        Pallet::<T, I>::create_split(
+            SystemOrigin::Signed(artist.clone()).into(),
+            shares_of::<T, I>(T::MaxSplitPayees::get()),
+        )?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist), 0, shares_of::<T, I>(p));
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::SplitUpdated {
+                id: 0,
+                version: 1,
+                deposit: T::SplitDeposit::get(),
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn lock_split() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        Pallet::<T, I>::create_split(
+            SystemOrigin::Signed(artist.clone()).into(),
+            shares_of::<T, I>(T::MaxSplitPayees::get()),
+        )?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist), 0);
+
+        assert_last_event::<T, I>(Event::<T, I>::SplitLocked { id: 0, version: 0 }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_artist_token() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        let symbol = BoundedVec::truncate_from(generate_string(T::NameMaxLength::get() as usize));
+        let supply: AssetBalanceOf<T, I> = 1_000_000u32.into();
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist.clone()), symbol, 12, supply);
+
+        assert!(Artists::<T, I>::get(&artist)
+            .and_then(|record| record.fan_token)
+            .is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_artist_collection() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        // The collection may have been created at the approval
+        Artists::<T, I>::mutate(&artist, |maybe_record| {
+            if let Some(record) = maybe_record {
+                record.collection = None;
+            }
+        });
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist.clone()));
+
+        assert!(Artists::<T, I>::get(&artist)
+            .and_then(|record| record.collection)
+            .is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_identity_policy() -> Result<(), BenchmarkError> {
+        let policy = IdentityPolicy {
+            required_judgement: Some((0, IdentityJudgement::KnownGood)),
+            copy_display_name: true,
+        };
+
+        #[extrinsic_call]
+        _(SystemOrigin::Root, policy.clone());
+
+        assert_last_event::<T, I>(Event::<T, I>::IdentityPolicySet { policy }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn follow_artist() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&artist)?;
+        let follower: T::AccountId = whitelisted_caller();
+        fund::<T, I>(&follower);
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(follower.clone()), 0);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::ArtistFollowed {
+                artist_id: 0,
+                follower,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unfollow_artist() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&artist)?;
+        let follower: T::AccountId = whitelisted_caller();
+        fund::<T, I>(&follower);
+        Pallet::<T, I>::follow_artist(SystemOrigin::Signed(follower.clone()).into(), 0)?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(follower.clone()), 0);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::ArtistUnfollowed {
+                artist_id: 0,
+                follower,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reap_followers(
+        n: Linear<1, { T::MaxFollowersReaped::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&artist)?;
+        // Leave `n` follows once the artist is revoked
+        follow_of::<T, I>(0, T::MaxFollowersReaped::get() + n)?;
+        Pallet::<T, I>::do_revoke_artist(&artist)?;
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(caller), 0);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::FollowersReaped {
+                artist_id: 0,
+                count: n,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn refresh_reputation() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        create_artist::<T, I>(&artist)?;
+        let later = frame_system::Pallet::<T>::block_number() + 1_000u32.into();
+        frame_system::Pallet::<T>::set_block_number(later);
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(caller), artist.clone());
+
+        assert!(Artists::<T, I>::get(&artist).is_some());
+        Ok(())
+    }
+
+    // Worst case: both artists have the longest lists of collaborations
+    #[benchmark]
+    fn dissolve_collaboration() -> Result<(), BenchmarkError> {
+        let proposer: T::AccountId = account("artist", 0, 0);
+        let counterparty: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&proposer)?;
+        create_artist::<T, I>(&counterparty)?;
+        let previous = (0..T::MaxCollaborations::get().saturating_sub(1))
+            .map(|id| id + 1)
+            .collect::<Vec<CollaborationId>>();
+        ArtistCollaborations::<T, I>::insert(
+            &proposer,
+            BoundedVec::truncate_from(previous.clone()),
+        );
+        ArtistCollaborations::<T, I>::insert(&counterparty, BoundedVec::truncate_from(previous));
+        Pallet::<T, I>::propose_collaboration(
+            SystemOrigin::Signed(proposer.clone()).into(),
+            counterparty.clone(),
+            T::Hashing::hash_of(&proposer),
+            BoundedVec::truncate_from(generate_string(T::MetadataMaxLength::get() as usize)),
+        )?;
+        Pallet::<T, I>::accept_collaboration(SystemOrigin::Signed(counterparty.clone()).into(), 0)?;
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(counterparty.clone()), 0);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::CollaborationDissolved {
+                id: 0,
+                dissolved_by: counterparty,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // Worst case: the split has all its versions and is listed last for the artist
+    #[benchmark]
+    fn retire_split() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = whitelisted_caller();
+        create_artist::<T, I>(&artist)?;
+        let previous = (0..T::MaxSplitsPerArtist::get().saturating_sub(1))
+            .map(|id| id + 1)
+            .collect::<Vec<SplitId>>();
+        ArtistSplits::<T, I>::insert(&artist, BoundedVec::truncate_from(previous));
+        Pallet::<T, I>::create_split(
+            SystemOrigin::Signed(artist.clone()).into(),
+            shares_of::<T, I>(T::MaxSplitPayees::get()),
+        )?;
+        for _ in 1..T::MaxSplitVersions::get() {
+            Pallet::<T, I>::update_split(
+                SystemOrigin::Signed(artist.clone()).into(),
+                0,
+                shares_of::<T, I>(T::MaxSplitPayees::get()),
+            )?;
+        }
+
+        #[extrinsic_call]
+        _(SystemOrigin::Signed(artist), 0);
+
+        assert_last_event::<T, I>(
+            Event::<T, I>::SplitRemoved {
+                id: 0,
+                deposit: T::SplitDeposit::get().saturating_mul(T::MaxSplitVersions::get().into()),
+            }
+            .into(),
         );
+        Ok(())
     }
 
-    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(false), crate::mock::Test)
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(false), crate::mock::Test);
 }
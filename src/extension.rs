@@ -0,0 +1,131 @@
+//! A signed extension waiving the fees of candidacy submissions and withdrawals.
+
+use super::*;
+use frame_support::{dispatch::DispatchInfo, traits::IsSubType};
+use sp_runtime::{
+    traits::{
+        DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension, SignedExtensionMetadata,
+    },
+    transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+};
+
+/// Wrap the fee payment extension `S` to waive the fees of `submit_candidacy`
+/// and `withdraw_candidacy`.
+///
+/// A submission is free when the creation deposit could be reserved from the
+/// signer, a withdrawal when the signer is a candidate. Each account is limited
+/// to `T::MaxFreeCalls` free calls per `T::FreeCallPeriod` blocks, past which
+/// the fees are charged by `S` as usual.
+///
+/// The extension is transparent in the metadata, where it appears as `S`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct CheckFreeCandidacy<T, I, S>(pub S, PhantomData<(T, I)>);
+
+impl<T, I, S> CheckFreeCandidacy<T, I, S> {
+    /// Create a new extension around the given fee payment extension.
+    pub fn new(inner: S) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+
+impl<T, I, S> From<S> for CheckFreeCandidacy<T, I, S> {
+    fn from(inner: S) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T, I, S: core::fmt::Debug> core::fmt::Debug for CheckFreeCandidacy<T, I, S> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckFreeCandidacy({:?})", self.0)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T, I, S> CheckFreeCandidacy<T, I, S>
+where
+    T: Config<I>,
+    I: 'static,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T, I>>,
+{
+    /// Whether the fees of the given call are waived for `who`.
+    fn is_free(who: &T::AccountId, call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+        call.is_sub_type().map_or(false, |call| {
+            Pallet::<T, I>::is_free_candidacy_call(who, call)
+        })
+    }
+}
+
+impl<T, I, S> SignedExtension for CheckFreeCandidacy<T, I, S>
+where
+    T: Config<I> + Send + Sync,
+    I: 'static + Send + Sync,
+    S: SignedExtension<AccountId = T::AccountId, Call = <T as frame_system::Config>::RuntimeCall>,
+    <T as frame_system::Config>::RuntimeCall:
+        Dispatchable<Info = DispatchInfo> + IsSubType<Call<T, I>>,
+{
+    // Transparent for the wallets, which only know about `S`
+    const IDENTIFIER: &'static str = S::IDENTIFIER;
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = S::AdditionalSigned;
+    // `None` when the fees were waived
+    type Pre = Option<S::Pre>;
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        self.0.additional_signed()
+    }
+
+    fn metadata() -> Vec<SignedExtensionMetadata> {
+        S::metadata()
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity {
+        if Self::is_free(who, call) {
+            Ok(ValidTransaction::default())
+        } else {
+            self.0.validate(who, call, info, len)
+        }
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if Self::is_free(who, call) {
+            Pallet::<T, I>::note_free_call(who);
+            Ok(None)
+        } else {
+            self.0.pre_dispatch(who, call, info, len).map(Some)
+        }
+    }
+
+    fn post_dispatch(
+        pre: Option<Self::Pre>,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        match pre {
+            Some(Some(pre)) => S::post_dispatch(Some(pre), info, post_info, len, result),
+            // The fees were waived, there is nothing to refund
+            Some(None) => Ok(()),
+            None => S::post_dispatch(None, info, post_info, len, result),
+        }
+    }
+}
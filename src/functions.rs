@@ -1,46 +1,973 @@
 use super::*;
-use frame_support::traits::Get;
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use allfeat_support::types::actors::artist::CandidateData;
+use frame_support::{ensure, storage::with_storage_layer, traits::Get};
+use frame_system::{
+    ensure_signed,
+    pallet_prelude::{BlockNumberFor, OriginFor},
+};
 
-impl<T: Config> Pallet<T> {
-    /// Check if the given account_id is an artist
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Check if the given account_id is an active artist, i.e. not suspended
     pub fn is_artist(account_id: &T::AccountId) -> bool {
-        <Artists<T>>::contains_key(account_id)
+        <Artists<T, I>>::get(account_id).map_or(false, |artist| !artist.is_suspended())
+    }
+
+    /// Check if the given account_id is a registered artist, even suspended
+    pub fn is_registered_artist(account_id: &T::AccountId) -> bool {
+        <Artists<T, I>>::contains_key(account_id)
     }
 
     /// Check if the given account_id is a candidate
     pub fn is_candidate(account_id: &T::AccountId) -> bool {
-        <Candidates<T>>::contains_key(account_id)
+        <Candidates<T, I>>::contains_key(account_id)
+    }
+
+    /// Check if the approval of the given candidate is waiting for its enactment
+    pub fn is_approval_pending(account_id: &T::AccountId) -> bool {
+        <PendingApprovals<T, I>>::contains_key(account_id)
+    }
+
+    /// Check if the given account_id is a verifier
+    pub fn is_verifier(account_id: &T::AccountId) -> bool {
+        <Verifiers<T, I>>::contains_key(account_id)
+    }
+
+    /// Return the verified NFT collection of the given artist, `None` if it isn't an
+    /// active artist or has no collection.
+    pub fn verified_collection_of(account_id: &T::AccountId) -> Option<CollectionIdOf<T, I>> {
+        <Artists<T, I>>::get(account_id)
+            .filter(|artist| !artist.is_suspended())
+            .and_then(|artist| artist.collection)
+    }
+
+    /// Return the reputation of the given artist, as of its last update.
+    pub fn reputation_of(account_id: &T::AccountId) -> Option<Reputation> {
+        <Artists<T, I>>::get(account_id).map(|artist| artist.reputation)
+    }
+
+    /// Return the verification level of the given artist
+    pub fn verification_level_of(account_id: &T::AccountId) -> Option<VerificationLevel> {
+        <Artists<T, I>>::get(account_id).map(|artist| artist.level)
+    }
+
+    /// Return the number of approvals given on a candidacy by the current verifiers.
+    ///
+    /// Only the current verifiers are looked up, bounded by `T::MaxVerifiers`, whatever
+    /// the number of verdicts given by former verifiers.
+    pub fn approvals_of(candidate: &T::AccountId) -> u32 {
+        <Verifiers<T, I>>::iter_keys()
+            .filter(|verifier| {
+                <Attestations<T, I>>::get(candidate, verifier) == Some(Verdict::Approved)
+            })
+            .count() as u32
+    }
+
+    /// Return the accounts of all the active artists, sorted by account id.
+    pub fn sorted_artists() -> Vec<T::AccountId> {
+        <SortedArtists<T, I>>::get().into_inner()
+    }
+
+    /// Return the accounts of all the current candidates, sorted by account id.
+    pub fn sorted_candidates() -> Vec<T::AccountId> {
+        <SortedCandidates<T, I>>::get().into_inner()
+    }
+
+    /// Return a page of the confirmed collaborations of an artist, in the order they were
+    /// confirmed, skipping the `start` first ones.
+    pub fn collaborations_of(
+        artist: &T::AccountId,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(CollaborationId, CollaborationOf<T, I>)> {
+        <ArtistCollaborations<T, I>>::get(artist)
+            .into_iter()
+            .skip(start as usize)
+            .take(limit as usize)
+            .filter_map(|id| {
+                <Collaborations<T, I>>::get(id).map(|collaboration| (id, collaboration))
+            })
+            .collect()
+    }
+
+    /// Return the artist having registered the work with the given ISRC, and the work.
+    pub fn work_by_isrc(isrc: &Isrc) -> Option<(T::AccountId, WorkOf<T, I>)> {
+        let artist = <WorksByIsrc<T, I>>::get(isrc)?;
+        <Catalog<T, I>>::get(&artist)
+            .into_iter()
+            .find(|(work, _)| work.isrc == *isrc)
+            .map(|(work, _)| (artist, work))
+    }
+
+    /// Ensure that the shares of a royalty split sum to 100%, each payee appearing once.
+    pub(crate) fn ensure_valid_shares(shares: &SharesOf<T, I>) -> DispatchResult {
+        let mut payees = BTreeSet::new();
+        let mut total: u64 = 0;
+        for (payee, share) in shares.iter() {
+            ensure!(payees.insert(payee), Error::<T, I>::DuplicatePayee);
+            total = total.saturating_add(share.deconstruct() as u64);
+        }
+        ensure!(
+            total == Perbill::one().deconstruct() as u64,
+            Error::<T, I>::InvalidSplitShares
+        );
+        Ok(())
     }
 
-    /// Ensure that the caller is an artist sending a signed tx
+    /// Ensure that the caller is an active artist sending a signed tx
     /// Same API of `ensure_signed()`
     pub fn ensure_artist(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
         let caller = ensure_signed(origin)?;
-        if !Self::is_artist(&caller) {
-            return Err(Error::<T>::NotAnArtist)?;
-        }
+        Self::ensure_active_artist(&caller)?;
+        Ok(caller)
+    }
+
+    /// Ensure that the caller is an active artist, sending a signed tx or dispatching
+    /// through `call_as_artist`.
+    pub fn ensure_artist_origin(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+        let caller = Self::ensure_signed_or_artist(origin)?;
+        Self::ensure_active_artist(&caller)?;
         Ok(caller)
     }
+
+    /// Ensure that the given account is a registered artist which isn't suspended.
+    fn ensure_active_artist(who: &T::AccountId) -> DispatchResult {
+        match <Artists<T, I>>::get(who) {
+            None => Err(Error::<T, I>::NotAnArtist)?,
+            Some(artist) if artist.is_suspended() => Err(Error::<T, I>::ArtistSuspended)?,
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Return the account of a signed origin or of the origin given by `call_as_artist`.
+    pub fn ensure_signed_or_artist(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+        if let Ok(caller) = ensure_signed(origin.clone()) {
+            return Ok(caller);
+        }
+        let origin: Result<RawOrigin<T::AccountId, I>, <T as Config<I>>::Origin> =
+            <T as Config<I>>::Origin::from(origin).into();
+        match origin {
+            Ok(RawOrigin::Artist(caller)) => Ok(caller),
+            _ => Err(DispatchError::BadOrigin),
+        }
+    }
+
     /// Ensure that the caller is a candidate sending a signed tx
     /// Same API of `ensure_signed()`
     pub fn ensure_candidate(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
         let caller = ensure_signed(origin)?;
         if !Self::is_candidate(&caller) {
-            return Err(Error::<T>::NotACandidate)?;
+            return Err(Error::<T, I>::NotACandidate)?;
         }
         Ok(caller)
     }
 
+    /// Ensure that the caller is a verifier sending a signed tx
+    /// Same API of `ensure_signed()`
+    pub fn ensure_verifier(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+        let caller = ensure_signed(origin)?;
+        if !Self::is_verifier(&caller) {
+            return Err(Error::<T, I>::NotAVerifier)?;
+        }
+        Ok(caller)
+    }
+
+    /// The calls dispatched by `who` through `call_as_candidate` in the ongoing era.
+    fn current_call_usage(who: &T::AccountId) -> CallUsage<BlockNumberFor<T>> {
+        let now = <frame_system::Pallet<T>>::block_number();
+        match <CandidateCallUsage<T, I>>::get(who) {
+            Some(usage) if now < usage.era_start.saturating_add(T::CandidateEraLength::get()) => {
+                usage
+            }
+            _ => CallUsage {
+                era_start: now,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// What the given candidate could still dispatch through `call_as_candidate`
+    /// in the ongoing era, `None` if it isn't a candidate.
+    pub fn candidate_call_budget(who: &T::AccountId) -> Option<CallBudget<BlockNumberFor<T>>> {
+        if !Self::is_candidate(who) {
+            return None;
+        }
+
+        let usage = Self::current_call_usage(who);
+        Some(CallBudget {
+            calls: T::MaxCandidateCalls::get().saturating_sub(usage.calls),
+            weight: T::MaxCandidateCallWeight::get().saturating_sub(usage.weight),
+            resets_at: usage.era_start.saturating_add(T::CandidateEraLength::get()),
+        })
+    }
+
+    /// Count a call of the given weight in the budget of a candidate,
+    /// failing if the budget of the era is exhausted.
+    pub(crate) fn consume_call_budget(who: &T::AccountId, weight: Weight) -> DispatchResult {
+        let mut usage = Self::current_call_usage(who);
+        usage.calls = usage.calls.saturating_add(1);
+        usage.weight = usage.weight.saturating_add(weight);
+        ensure!(
+            usage.calls <= T::MaxCandidateCalls::get()
+                && usage.weight.all_lte(T::MaxCandidateCallWeight::get()),
+            Error::<T, I>::CandidateRateLimited
+        );
+
+        <CandidateCallUsage<T, I>>::insert(who, usage);
+        Ok(())
+    }
+
+    /// The number of fee-less calls `who` could still make in the current period.
+    pub fn free_calls_left(who: &T::AccountId) -> u32 {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let used = match <FreeCalls<T, I>>::get(who) {
+            Some((start, count)) if now < start.saturating_add(T::FreeCallPeriod::get()) => count,
+            _ => 0,
+        };
+        T::MaxFreeCalls::get().saturating_sub(used)
+    }
+
+    /// Whether the fees of the given call could be waived for `who`.
+    pub fn is_free_candidacy_call(who: &T::AccountId, call: &Call<T, I>) -> bool {
+        if Self::free_calls_left(who) == 0 {
+            return false;
+        }
+
+        match call {
+            Call::submit_candidacy { name } => {
+                let policy = Self::identity_policy();
+                Self::candidate_name(who, name.clone(), &policy).len()
+                    <= T::NameMaxLength::get() as usize
+                    && !Self::is_registered_artist(who)
+                    && !Self::is_candidate(who)
+                    && Self::ensure_identity_judgement(who, &policy).is_ok()
+                    && (<SortedCandidates<T, I>>::decode_len().unwrap_or(0) as u32)
+                        < T::MaxCandidates::get()
+                    && T::Currency::can_reserve(who, T::CreationDepositAmount::get())
+            }
+            Call::withdraw_candidacy {} => Self::is_candidate(who),
+            _ => false,
+        }
+    }
+
+    /// Count a fee-less call of `who`, starting a new period if the last one ended.
+    pub(crate) fn note_free_call(who: &T::AccountId) {
+        let now = <frame_system::Pallet<T>>::block_number();
+        <FreeCalls<T, I>>::mutate(who, |usage| match usage {
+            Some((start, count)) if now < start.saturating_add(T::FreeCallPeriod::get()) => {
+                *count = count.saturating_add(1)
+            }
+            _ => *usage = Some((now, 1)),
+        });
+    }
+
+    /// Ensure that the origin is either `T::AdminOrigin` or a verifier sending a signed tx,
+    /// returning the verifier if any.
+    pub fn ensure_admin_or_verifier(
+        origin: OriginFor<T>,
+    ) -> Result<Option<T::AccountId>, DispatchError> {
+        match T::AdminOrigin::try_origin(origin) {
+            Ok(_) => Ok(None),
+            Err(origin) => Self::ensure_verifier(origin).map(Some),
+        }
+    }
+
     pub fn reserve_deposit(caller: &T::AccountId) -> DispatchResult {
         let deposit = T::CreationDepositAmount::get();
-        T::Currency::reserve(caller, deposit).map_err(|_| Error::<T>::NotEnoughFunds)?;
+        T::Currency::reserve(caller, deposit).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
         Ok(())
     }
 
-    pub fn unreserve_deposit(to: &T::AccountId) -> DispatchResult {
+    /// Release the creation deposit of the given candidate to its sponsor, if any, or to
+    /// itself, returning the depositor and the released deposit.
+    pub fn unreserve_deposit(
+        who: &T::AccountId,
+    ) -> Result<(T::AccountId, BalanceOf<T, I>), DispatchError> {
+        let deposit =
+            <CandidacyDeposits<T, I>>::take(who).unwrap_or_else(T::CreationDepositAmount::get);
+        let depositor = <Sponsors<T, I>>::take(who).unwrap_or_else(|| who.clone());
+        T::Currency::unreserve(&depositor, deposit);
+        Ok((depositor, deposit))
+    }
+
+    /// Award `T::ChallengerReward` of an amount held from `who` to the challenger
+    /// and slash the rest, returning the reward.
+    pub(crate) fn confiscate_deposit(
+        who: &T::AccountId,
+        amount: BalanceOf<T, I>,
+        challenger: &T::AccountId,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        let reward = T::ChallengerReward::get().mul_floor(amount);
+        T::Currency::repatriate_reserved(who, challenger, reward, BalanceStatus::Free)?;
+        let (imbalance, _) = T::Currency::slash_reserved(who, amount.saturating_sub(reward));
+        T::Slashed::on_unbalanced(imbalance);
+        Ok(reward)
+    }
+
+    /// Register a candidacy for `who`, holding the creation deposit from `depositor`.
+    pub(crate) fn do_submit_candidacy(
+        who: &T::AccountId,
+        name: Vec<u8>,
+        depositor: &T::AccountId,
+    ) -> DispatchResult {
+        // Check if the account isn't neither a candidate nor an artist
+        ensure!(
+            !Self::is_registered_artist(who),
+            Error::<T, I>::AlreadyAnArtist
+        );
+        ensure!(!Self::is_candidate(who), Error::<T, I>::AlreadyACandidate);
+
+        let policy = Self::identity_policy();
+        Self::ensure_identity_judgement(who, &policy)?;
+        let name = Self::candidate_name(who, name, &policy);
+
+        let candidate = CandidateData {
+            name: name.try_into().map_err(|_| Error::<T, I>::NameTooLong)?,
+            created_at: <frame_system::Pallet<T>>::block_number(),
+        };
+
+        Self::insert_sorted_candidate(who)?;
+        Self::reserve_deposit(depositor)?;
         let deposit = T::CreationDepositAmount::get();
-        T::Currency::unreserve(to, deposit);
+
+        <Candidates<T, I>>::insert(who, &candidate);
+        <CandidacyDeposits<T, I>>::insert(who, deposit);
+
+        Self::deposit_event(Event::<T, I>::CandidateAdded {
+            candidate: who.clone(),
+            name: candidate.name,
+            deposit,
+            created_at: candidate.created_at,
+        });
         Ok(())
     }
+
+    /// Signal `T::MembershipChanged` that artists joined and/or left the artist set.
+    pub(crate) fn notify_artists_changed(incoming: &[T::AccountId], outgoing: &[T::AccountId]) {
+        let mut incoming = incoming.to_vec();
+        let mut outgoing = outgoing.to_vec();
+        incoming.sort();
+        outgoing.sort();
+
+        let members = <SortedArtists<T, I>>::mutate(|members| {
+            for who in &outgoing {
+                if let Ok(index) = members.binary_search(who) {
+                    members.remove(index);
+                }
+            }
+            for who in &incoming {
+                if let Err(index) = members.binary_search(who) {
+                    // Can't overflow, the registered artists are bounded by `T::MaxArtists`
+                    let _ = members.try_insert(index, who.clone());
+                }
+            }
+            members.to_vec()
+        });
+
+        T::MembershipChanged::change_members_sorted(&incoming, &outgoing, &members);
+    }
+
+    /// Add an account to the sorted candidates, if there is room for it.
+    pub(crate) fn insert_sorted_candidate(who: &T::AccountId) -> DispatchResult {
+        <SortedCandidates<T, I>>::try_mutate(|candidates| {
+            if let Err(index) = candidates.binary_search(who) {
+                candidates
+                    .try_insert(index, who.clone())
+                    .map_err(|_| Error::<T, I>::TooManyCandidates)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Remove a candidacy along with its attestations, evidence and endorsements.
+    /// The candidacy deposit itself is left untouched.
+    pub(crate) fn remove_candidacy(candidate: &T::AccountId) {
+        <Candidates<T, I>>::remove(candidate);
+        <SortedCandidates<T, I>>::mutate(|candidates| {
+            if let Ok(index) = candidates.binary_search(candidate) {
+                candidates.remove(index);
+            }
+        });
+        Self::cancel_scheduled_approval(candidate);
+        Self::clear_attestations(candidate);
+        Self::clear_evidence(candidate);
+        <Endorsements<T, I>>::remove(candidate);
+        <CandidateCallUsage<T, I>>::remove(candidate);
+    }
+
+    /// Ensure another artist could be registered.
+    pub(crate) fn ensure_artist_capacity() -> DispatchResult {
+        ensure!(
+            <Artists<T, I>>::count() < T::MaxArtists::get(),
+            Error::<T, I>::TooManyArtists
+        );
+        Ok(())
+    }
+
+    /// Approve a candidate, leveling it up after `T::ApprovalDelay` blocks or
+    /// right away if there is no delay.
+    pub(crate) fn schedule_approval(who: &T::AccountId, source: ApprovalSource) -> DispatchResult {
+        ensure!(
+            !Self::is_registered_artist(who),
+            Error::<T, I>::AlreadyAnArtist
+        );
+        ensure!(Self::is_candidate(who), Error::<T, I>::CandidateNotFound);
+        ensure!(
+            !Self::is_approval_pending(who),
+            Error::<T, I>::ApprovalAlreadyScheduled
+        );
+
+        let delay = T::ApprovalDelay::get();
+        if delay.is_zero() {
+            return Self::do_approve_candidacy(who, source);
+        }
+        // Checked again once enacted, the judgement could be withdrawn meanwhile
+        Self::ensure_identity_judgement(who, &Self::identity_policy())?;
+
+        let enact_at = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
+        <ApprovalQueue<T, I>>::try_append(enact_at, who)
+            .map_err(|_| Error::<T, I>::TooManyScheduledApprovals)?;
+        <PendingApprovals<T, I>>::insert(who, (enact_at, source));
+
+        Self::deposit_event(Event::<T, I>::ApprovalScheduled {
+            candidate: who.clone(),
+            enact_at,
+            source,
+        });
+        Ok(())
+    }
+
+    /// Schedule the approval of a candidate reaching a threshold. A failure is reported
+    /// through `ApprovalFailed` rather than reverting the call that reached the threshold.
+    pub(crate) fn try_schedule_approval(who: &T::AccountId, source: ApprovalSource) {
+        if let Err(error) = with_storage_layer(|| Self::schedule_approval(who, source)) {
+            Self::deposit_event(Event::<T, I>::ApprovalFailed {
+                candidate: who.clone(),
+                error,
+            });
+        }
+    }
+
+    /// Cancel the scheduled approval of a candidate, returning whether there was one.
+    pub(crate) fn cancel_scheduled_approval(who: &T::AccountId) -> bool {
+        let Some((enact_at, _)) = <PendingApprovals<T, I>>::take(who) else {
+            return false;
+        };
+        <ApprovalQueue<T, I>>::mutate_exists(enact_at, |maybe_queue| {
+            if let Some(queue) = maybe_queue {
+                queue.retain(|candidate| candidate != who);
+                if queue.is_empty() {
+                    *maybe_queue = None;
+                }
+            }
+        });
+        true
+    }
+
+    /// Level up the candidates whose approval is enacted at the given block.
+    pub(crate) fn enact_scheduled_approvals(n: BlockNumberFor<T>) -> Weight {
+        let queue = <ApprovalQueue<T, I>>::take(n);
+        let count = queue.len() as u32;
+
+        for candidate in queue {
+            let Some((_, source)) = <PendingApprovals<T, I>>::take(&candidate) else {
+                continue;
+            };
+            match Self::do_approve_candidacy(&candidate, source) {
+                Ok(()) => Self::deposit_event(Event::<T, I>::ApprovalEnacted { candidate }),
+                Err(error) => {
+                    Self::deposit_event(Event::<T, I>::ApprovalFailed { candidate, error })
+                }
+            }
+        }
+
+        T::WeightInfo::enact_approvals(count)
+    }
+
+    /// Reinstate the artists whose suspension ends at the given block.
+    pub(crate) fn lift_expired_suspensions(n: BlockNumberFor<T>) -> Weight {
+        let expired = <SuspensionExpiries<T, I>>::take(n);
+        let count = expired.len() as u32;
+
+        let mut lifted = Vec::new();
+        for artist in expired {
+            if Self::do_lift_suspension(&artist, false) {
+                lifted.push(artist);
+            }
+        }
+        if !lifted.is_empty() {
+            Self::notify_artists_changed(&lifted, &[]);
+        }
+
+        T::WeightInfo::lift_suspensions(count)
+    }
+
+    /// Level up a candidate to be an artist.
+    pub(crate) fn do_approve_candidacy(
+        who: &T::AccountId,
+        source: ApprovalSource,
+    ) -> DispatchResult {
+        ensure!(
+            !Self::is_registered_artist(who),
+            Error::<T, I>::AlreadyAnArtist
+        );
+        Self::ensure_artist_capacity()?;
+
+        let candidate =
+            <Candidates<T, I>>::try_get(who).or_else(|_| Err(Error::<T, I>::CandidateNotFound))?;
+        Self::ensure_identity_judgement(who, &Self::identity_policy())?;
+
+        let level = VerificationLevel::default();
+        let level_deposit = T::LevelDeposit::convert(level);
+        T::Currency::reserve(who, level_deposit).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+        let creation_deposit =
+            <CandidacyDeposits<T, I>>::take(who).unwrap_or_else(T::CreationDepositAmount::get);
+
+        // The approval goes on without collection if it couldn't be created, the artist
+        // could still create it afterwards
+        let collection = with_storage_layer(|| Self::do_create_collection(who)).ok();
+
+        let artist = ArtistRecord {
+            id: Self::assign_artist_id(who),
+            name: candidate.name,
+            metadata: Default::default(),
+            created_at: <frame_system::Pallet<T>>::block_number(),
+            level,
+            deposit: creation_deposit.saturating_add(level_deposit),
+            deposit_exempt: false,
+            fan_token: None,
+            collection,
+            followers: 0,
+            reputation: Zero::zero(),
+            reputation_stats: ReputationStats {
+                endorsements: <Endorsements<T, I>>::decode_len(who).unwrap_or(0) as u32,
+                ..Default::default()
+            },
+            status: ArtistStatus::Active,
+        };
+
+        <Artists<T, I>>::insert(who, artist.clone());
+        Self::update_reputation(who, |_| ());
+
+        Self::remove_candidacy(who);
+
+        Self::notify_artists_changed(&[who.clone()], &[]);
+
+        Self::deposit_event(Event::<T, I>::CandidateApproved {
+            candidate: who.clone(),
+            name: artist.name,
+            deposit: artist.deposit,
+            source,
+        });
+        Ok(())
+    }
+
+    /// Register an artist without going through the candidacy workflow.
+    /// The candidacy of `who`, if any, is removed and its deposits released.
+    pub(crate) fn do_force_register_artist(
+        who: &T::AccountId,
+        profile: ProfileOf<T, I>,
+        deposit_exempt: bool,
+    ) -> DispatchResult {
+        ensure!(
+            !Self::is_registered_artist(who),
+            Error::<T, I>::AlreadyAnArtist
+        );
+        Self::ensure_artist_capacity()?;
+
+        if Self::is_candidate(who) {
+            Self::remove_candidacy(who);
+            Self::unreserve_deposit(who)?;
+        }
+
+        let level = VerificationLevel::default();
+        let deposit = if deposit_exempt {
+            Zero::zero()
+        } else {
+            T::CreationDepositAmount::get().saturating_add(T::LevelDeposit::convert(level))
+        };
+        T::Currency::reserve(who, deposit).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+
+        let artist = ArtistRecord {
+            id: Self::assign_artist_id(who),
+            name: profile.name,
+            metadata: profile.metadata,
+            created_at: <frame_system::Pallet<T>>::block_number(),
+            level,
+            deposit,
+            deposit_exempt,
+            fan_token: None,
+            collection: None,
+            followers: 0,
+            reputation: Zero::zero(),
+            reputation_stats: Default::default(),
+            status: ArtistStatus::Active,
+        };
+
+        <Artists<T, I>>::insert(who, &artist);
+        Self::update_reputation(who, |_| ());
+
+        Self::notify_artists_changed(&[who.clone()], &[]);
+
+        Self::deposit_event(Event::<T, I>::ArtistRegistered {
+            artist: who.clone(),
+            id: artist.id,
+            name: artist.name,
+            deposit,
+            deposit_exempt,
+        });
+        Ok(())
+    }
+
+    /// Create the fan token of an artist at the next fan token index, holding its
+    /// deposit, and mint its supply to the artist.
+    pub(crate) fn do_create_artist_token(
+        artist: &T::AccountId,
+        symbol: Vec<u8>,
+        decimals: u8,
+        supply: AssetBalanceOf<T, I>,
+    ) -> DispatchResult {
+        let record = <Artists<T, I>>::get(artist).ok_or(Error::<T, I>::ArtistNotFound)?;
+        ensure!(
+            record.fan_token.is_none(),
+            Error::<T, I>::FanTokenAlreadyCreated
+        );
+        let index = <NextFanToken<T, I>>::get();
+        let asset = T::FanTokenId::convert(index).ok_or(Error::<T, I>::FanTokensDisabled)?;
+
+        let deposit = T::FanTokenDeposit::get();
+        T::Currency::reserve(artist, deposit).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+        T::Assets::create_token(
+            asset.clone(),
+            artist,
+            record.name.into_inner(),
+            symbol,
+            decimals,
+            supply,
+        )?;
+
+        <NextFanToken<T, I>>::put(index.saturating_add(1));
+        <Artists<T, I>>::mutate(artist, |maybe_record| {
+            if let Some(record) = maybe_record {
+                record.fan_token = Some((asset.clone(), deposit));
+            }
+        });
+
+        Self::deposit_event(Event::<T, I>::FanTokenCreated {
+            artist: artist.clone(),
+            asset,
+            supply,
+            deposit,
+        });
+        Ok(())
+    }
+
+    /// The name a candidacy of `who` is registered under, its display name when the
+    /// policy copies it.
+    pub(crate) fn candidate_name(
+        who: &T::AccountId,
+        name: Vec<u8>,
+        policy: &IdentityPolicy,
+    ) -> Vec<u8> {
+        policy
+            .copy_display_name
+            .then(|| T::Identity::display_name(who))
+            .flatten()
+            .unwrap_or(name)
+    }
+
+    /// Ensure `who` has the identity judgement required by the policy, if any.
+    pub(crate) fn ensure_identity_judgement(
+        who: &T::AccountId,
+        policy: &IdentityPolicy,
+    ) -> DispatchResult {
+        if let Some((registrar, minimum)) = policy.required_judgement {
+            ensure!(
+                T::Identity::judgement(who, registrar)
+                    .map_or(false, |judgement| judgement >= minimum),
+                Error::<T, I>::IdentityJudgementRequired
+            );
+        }
+        Ok(())
+    }
+
+    /// Update the reputation stats of an artist and score its reputation again, as
+    /// it ages. `ReputationChanged` is emitted when the reputation changed.
+    pub(crate) fn update_reputation(who: &T::AccountId, update: impl FnOnce(&mut ReputationStats)) {
+        let now = <frame_system::Pallet<T>>::block_number();
+        <Artists<T, I>>::mutate(who, |maybe_record| {
+            let Some(record) = maybe_record else {
+                return;
+            };
+            update(&mut record.reputation_stats);
+
+            let age = now.saturating_sub(record.created_at);
+            let reputation = T::ReputationPolicy::reputation(&record.reputation_stats, age);
+            if reputation != record.reputation {
+                Self::deposit_event(Event::<T, I>::ReputationChanged {
+                    artist: who.clone(),
+                    previous: record.reputation,
+                    reputation,
+                });
+                record.reputation = reputation;
+            }
+        });
+    }
+
+    /// Create the verified collection of an artist.
+    pub(crate) fn do_create_collection(
+        artist: &T::AccountId,
+    ) -> Result<CollectionIdOf<T, I>, DispatchError> {
+        let collection = T::Collections::create_collection(artist)?;
+
+        Self::deposit_event(Event::<T, I>::CollectionCreated {
+            artist: artist.clone(),
+            collection: collection.clone(),
+        });
+        Ok(collection)
+    }
+
+    /// Give the next artist identifier to `who`.
+    pub(crate) fn assign_artist_id(who: &T::AccountId) -> ArtistId {
+        let id = <NextArtistId<T, I>>::mutate(|next| {
+            let id = *next;
+            *next = next.saturating_add(1);
+            id
+        });
+        <ArtistAccounts<T, I>>::insert(id, who);
+        id
+    }
+
+    /// Remove an artist from the artist membership and return its record.
+    /// The artist deposit is left held, for the caller to release or slash it.
+    pub(crate) fn do_revoke_artist(who: &T::AccountId) -> Result<ArtistOf<T, I>, DispatchError> {
+        let artist = <Artists<T, I>>::take(who).ok_or(Error::<T, I>::ArtistNotFound)?;
+        <ArtistAccounts<T, I>>::remove(artist.id);
+        Self::clear_catalog(who);
+        Self::do_reap_followers(artist.id, T::MaxFollowersReaped::get());
+        Self::clear_collaborations(who);
+        Self::clear_splits(who);
+        if let Some((_, deposit)) = &artist.fan_token {
+            T::Currency::unreserve(who, *deposit);
+        }
+
+        match artist.status {
+            ArtistStatus::Active => Self::notify_artists_changed(&[], &[who.clone()]),
+            ArtistStatus::Suspended { until, .. } => Self::remove_suspension_expiry(who, until),
+        }
+
+        Self::deposit_event(Event::<T, I>::ArtistRevoked {
+            artist: who.clone(),
+            name: artist.name.clone(),
+            deposit: artist.deposit,
+            previous_status: artist.status.clone(),
+        });
+        Ok(artist)
+    }
+
+    /// Reinstate a suspended artist, returning whether it was suspended.
+    /// `early` tells whether the suspension is lifted before its end.
+    /// The caller is responsible for the expiry schedule and the membership signal.
+    pub(crate) fn do_lift_suspension(who: &T::AccountId, early: bool) -> bool {
+        let lifted = <Artists<T, I>>::mutate(who, |maybe_record| match maybe_record {
+            Some(record) => match core::mem::take(&mut record.status) {
+                ArtistStatus::Suspended { until, .. } => Some(until),
+                ArtistStatus::Active => None,
+            },
+            None => None,
+        });
+
+        if let Some(until) = lifted {
+            Self::deposit_event(Event::<T, I>::ArtistUnsuspended {
+                artist: who.clone(),
+                until,
+                early,
+            });
+        }
+        lifted.is_some()
+    }
+
+    /// Remove an artist from the suspensions ending at the given block.
+    pub(crate) fn remove_suspension_expiry(who: &T::AccountId, until: BlockNumberFor<T>) {
+        <SuspensionExpiries<T, I>>::mutate_exists(until, |maybe_expiries| {
+            if let Some(expiries) = maybe_expiries {
+                expiries.retain(|artist| artist != who);
+                if expiries.is_empty() {
+                    *maybe_expiries = None;
+                }
+            }
+        });
+    }
+
+    /// Move an artist to the given verification level, holding or releasing
+    /// the difference between the two level deposits.
+    pub(crate) fn do_set_verification_level(
+        artist: &T::AccountId,
+        level: VerificationLevel,
+        verifier: Option<T::AccountId>,
+    ) -> DispatchResult {
+        <Artists<T, I>>::try_mutate(artist, |maybe_record| -> DispatchResult {
+            let record = maybe_record.as_mut().ok_or(Error::<T, I>::ArtistNotFound)?;
+            let previous = record.level;
+            ensure!(previous != level, Error::<T, I>::SameVerificationLevel);
+
+            // Nothing is held from a deposit exempt artist, whatever its level
+            if !record.deposit_exempt {
+                let previous_deposit = T::LevelDeposit::convert(previous);
+                let new_deposit = T::LevelDeposit::convert(level);
+                if new_deposit > previous_deposit {
+                    let extra = new_deposit.saturating_sub(previous_deposit);
+                    T::Currency::reserve(artist, extra)
+                        .map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+                    record.deposit = record.deposit.saturating_add(extra);
+                } else {
+                    let excess = previous_deposit.saturating_sub(new_deposit);
+                    T::Currency::unreserve(artist, excess);
+                    record.deposit = record.deposit.saturating_sub(excess);
+                }
+            }
+            record.level = level;
+
+            Self::deposit_event(Event::<T, I>::VerificationLevelChanged {
+                artist: artist.clone(),
+                previous,
+                level,
+                deposit: record.deposit,
+                verifier,
+            });
+            Ok(())
+        })
+    }
+
+    /// Remove a confirmed collaboration from the listings of both artists.
+    pub(crate) fn do_dissolve_collaboration(
+        id: CollaborationId,
+        collaboration: &CollaborationOf<T, I>,
+        dissolved_by: &T::AccountId,
+    ) {
+        for artist in [&collaboration.proposer, &collaboration.counterparty] {
+            <ArtistCollaborations<T, I>>::mutate_exists(artist, |maybe_ids| {
+                if let Some(ids) = maybe_ids {
+                    ids.retain(|other| *other != id);
+                    if ids.is_empty() {
+                        *maybe_ids = None;
+                    }
+                }
+            });
+        }
+        <Collaborations<T, I>>::remove(id);
+
+        Self::deposit_event(Event::<T, I>::CollaborationDissolved {
+            id,
+            dissolved_by: dissolved_by.clone(),
+        });
+    }
+
+    /// Remove a proposal from the pending listings of both artists.
+    pub(crate) fn remove_pending_collaboration(
+        id: CollaborationId,
+        collaboration: &CollaborationOf<T, I>,
+    ) {
+        for artist in [&collaboration.proposer, &collaboration.counterparty] {
+            <PendingCollaborations<T, I>>::mutate_exists(artist, |maybe_ids| {
+                if let Some(ids) = maybe_ids {
+                    ids.retain(|other| *other != id);
+                    if ids.is_empty() {
+                        *maybe_ids = None;
+                    }
+                }
+            });
+        }
+    }
+
+    /// Cancel a proposal which isn't confirmed yet, releasing the deposit of the proposer.
+    pub(crate) fn do_cancel_collaboration(
+        id: CollaborationId,
+        collaboration: &CollaborationOf<T, I>,
+        cancelled_by: &T::AccountId,
+    ) {
+        Self::remove_pending_collaboration(id, collaboration);
+        <Collaborations<T, I>>::remove(id);
+        T::Currency::unreserve(&collaboration.proposer, collaboration.deposit);
+
+        Self::deposit_event(Event::<T, I>::CollaborationCancelled {
+            id,
+            cancelled_by: cancelled_by.clone(),
+        });
+    }
+
+    /// Dissolve the confirmed collaborations of an artist and cancel its pending
+    /// proposals, releasing the deposits held for them.
+    pub(crate) fn clear_collaborations(artist: &T::AccountId) {
+        for id in <ArtistCollaborations<T, I>>::get(artist) {
+            if let Some(collaboration) = <Collaborations<T, I>>::get(id) {
+                Self::do_dissolve_collaboration(id, &collaboration, artist);
+            }
+        }
+        for id in <PendingCollaborations<T, I>>::get(artist) {
+            if let Some(collaboration) = <Collaborations<T, I>>::get(id) {
+                Self::do_cancel_collaboration(id, &collaboration, artist);
+            }
+        }
+    }
+
+    /// Release the deposits of the works of an artist and remove them.
+    pub(crate) fn clear_catalog(artist: &T::AccountId) {
+        for (work, deposit) in <Catalog<T, I>>::take(artist) {
+            <WorksByIsrc<T, I>>::remove(work.isrc);
+            T::Currency::unreserve(artist, deposit);
+        }
+    }
+
+    /// Remove the royalty splits of an artist with their versions, releasing their
+    /// deposits.
+    pub(crate) fn clear_splits(artist: &T::AccountId) {
+        for id in <ArtistSplits<T, I>>::take(artist) {
+            if let Some(split) = <RoyaltySplits<T, I>>::get(id) {
+                Self::do_remove_split(id, &split);
+            }
+        }
+    }
+
+    /// Remove a royalty split with all its versions, releasing their deposits. The
+    /// split must already be unlisted from the splits of its owner.
+    pub(crate) fn do_remove_split(id: SplitId, split: &RoyaltySplitOf<T, I>) {
+        <RoyaltySplits<T, I>>::remove(id);
+        let _ = <SplitVersions<T, I>>::clear_prefix(id, T::MaxSplitVersions::get(), None);
+        T::Currency::unreserve(&split.owner, split.deposit);
+
+        Self::deposit_event(Event::<T, I>::SplitRemoved {
+            id,
+            deposit: split.deposit,
+        });
+    }
+
+    /// Release up to `limit` follows of an artist, returning how many were released.
+    pub(crate) fn do_reap_followers(artist_id: ArtistId, limit: u32) -> u32 {
+        let mut count = 0;
+        for (follower, deposit) in <Followers<T, I>>::drain_prefix(artist_id).take(limit as usize) {
+            T::Currency::unreserve(&follower, deposit);
+            count += 1;
+        }
+
+        if count > 0 {
+            Self::deposit_event(Event::<T, I>::FollowersReaped { artist_id, count });
+        }
+        count
+    }
+
+    /// Release the deposits of the evidence attached to a candidacy and remove them.
+    pub(crate) fn clear_evidence(candidate: &T::AccountId) {
+        for (_, deposit) in <CandidacyEvidence<T, I>>::take(candidate) {
+            T::Currency::unreserve(candidate, deposit);
+        }
+    }
+
+    /// Release the fees of the pending attestation requests of a candidate and
+    /// forget the verdicts given on it.
+    pub(crate) fn clear_attestations(candidate: &T::AccountId) {
+        for (_, fee) in <AttestationRequests<T, I>>::drain_prefix(candidate) {
+            T::Currency::unreserve(candidate, fee);
+        }
+        let _ = <Attestations<T, I>>::clear_prefix(candidate, u32::MAX, None);
+    }
 }
@@ -1,25 +1,117 @@
 use super::*;
+use crate::traits::RoyaltySplitProvider;
 use allfeat_support::traits::actors::{artist::ArtistStorage, ActorStorage};
+#[cfg(feature = "runtime-benchmarks")]
+use allfeat_support::types::actors::artist::CandidateData;
+use frame_support::traits::{Contains, SortedMembers};
 
-impl<T: Config> ActorStorage<T::AccountId> for Pallet<T> {
-    /// THIS SHOULDN'T BE USED
-    /// We don't want to use this function as the artist module is divided in two actors
-    fn is_actor(_: &T::AccountId) -> bool {
-        return false;
+impl<T: Config<I>, I: 'static> ActorStorage<T::AccountId> for Pallet<T, I> {
+    /// An account is considered as an actor once it is a certified artist.
+    /// Candidates are not actors yet, see `ArtistStorage::is_candidate` for them.
+    fn is_actor(account_id: &T::AccountId) -> bool {
+        Self::is_artist(account_id)
     }
 }
 
-impl<T: Config> ArtistStorage<T::AccountId, CandidateOf<T>, ArtistOf<T>> for Pallet<T> {
+impl<T: Config<I>, I: 'static> ArtistStorage<T::AccountId, CandidateOf<T, I>, ArtistOf<T, I>>
+    for Pallet<T, I>
+{
     fn is_candidate(account_id: &T::AccountId) -> bool {
-        <Candidates<T>>::contains_key(account_id)
+        <Candidates<T, I>>::contains_key(account_id)
     }
     fn is_artist(account_id: &T::AccountId) -> bool {
-        <Artists<T>>::contains_key(account_id)
+        Pallet::<T, I>::is_artist(account_id)
     }
-    fn candidate(account_id: &T::AccountId) -> Option<CandidateOf<T>> {
-        <Candidates<T>>::get(account_id)
+    fn candidate(account_id: &T::AccountId) -> Option<CandidateOf<T, I>> {
+        <Candidates<T, I>>::get(account_id)
     }
-    fn artist(account_id: &T::AccountId) -> Option<ArtistOf<T>> {
-        <Artists<T>>::get(account_id)
+    fn artist(account_id: &T::AccountId) -> Option<ArtistOf<T, I>> {
+        <Artists<T, I>>::get(account_id)
+    }
+}
+
+impl<T: Config<I>, I: 'static> RoyaltySplitProvider<T::AccountId> for Pallet<T, I> {
+    fn active_split(id: SplitId) -> Option<Vec<(T::AccountId, Perbill)>> {
+        let split = <RoyaltySplits<T, I>>::get(id)?;
+        Self::split_at(id, split.version)
+    }
+
+    fn split_at(id: SplitId, version: SplitVersion) -> Option<Vec<(T::AccountId, Perbill)>> {
+        <SplitVersions<T, I>>::get(id, version).map(|shares| shares.into_inner())
+    }
+}
+
+/// The set of the certified artists, to be plugged in any FRAME component
+/// expecting a `Contains` or `SortedMembers` implementation.
+pub struct ArtistMembers<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> Contains<T::AccountId> for ArtistMembers<T, I> {
+    fn contains(account_id: &T::AccountId) -> bool {
+        Pallet::<T, I>::is_artist(account_id)
+    }
+}
+
+impl<T: Config<I>, I: 'static> SortedMembers<T::AccountId> for ArtistMembers<T, I> {
+    fn sorted_members() -> Vec<T::AccountId> {
+        Pallet::<T, I>::sorted_artists()
+    }
+
+    fn contains(account_id: &T::AccountId) -> bool {
+        Pallet::<T, I>::is_artist(account_id)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(account_id: &T::AccountId) {
+        let artist = ArtistRecord {
+            id: Pallet::<T, I>::assign_artist_id(account_id),
+            name: Default::default(),
+            metadata: Default::default(),
+            created_at: <frame_system::Pallet<T>>::block_number(),
+            level: Default::default(),
+            deposit: Default::default(),
+            deposit_exempt: true,
+            fan_token: None,
+            collection: None,
+            followers: 0,
+            reputation: 0,
+            reputation_stats: Default::default(),
+            status: ArtistStatus::Active,
+        };
+        <Artists<T, I>>::insert(account_id, artist);
+        <SortedArtists<T, I>>::mutate(|members| {
+            if let Err(index) = members.binary_search(account_id) {
+                let _ = members.try_insert(index, account_id.clone());
+            }
+        });
+    }
+}
+
+/// The set of the current candidates, to be plugged in any FRAME component
+/// expecting a `Contains` or `SortedMembers` implementation.
+pub struct CandidateMembers<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> Contains<T::AccountId> for CandidateMembers<T, I> {
+    fn contains(account_id: &T::AccountId) -> bool {
+        Pallet::<T, I>::is_candidate(account_id)
+    }
+}
+
+impl<T: Config<I>, I: 'static> SortedMembers<T::AccountId> for CandidateMembers<T, I> {
+    fn sorted_members() -> Vec<T::AccountId> {
+        Pallet::<T, I>::sorted_candidates()
+    }
+
+    fn contains(account_id: &T::AccountId) -> bool {
+        Pallet::<T, I>::is_candidate(account_id)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(account_id: &T::AccountId) {
+        let candidate = CandidateData {
+            name: Default::default(),
+            created_at: <frame_system::Pallet<T>>::block_number(),
+        };
+        <Candidates<T, I>>::insert(account_id, candidate);
+        let _ = Pallet::<T, I>::insert_sorted_candidate(account_id);
     }
 }
@@ -8,19 +8,25 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
+pub mod migrations;
+pub mod runtime_api;
+pub mod traits;
 pub mod weights;
 
+mod extension;
 mod functions;
 mod impls;
 mod types;
 
+pub use extension::CheckFreeCandidacy;
+pub use impls::{ArtistMembers, CandidateMembers};
 pub use types::*;
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::pallet_prelude::DispatchError;
-use frame_support::traits::EnsureOrigin;
+use frame_support::traits::{BalanceStatus, ChangeMembers, EnsureOrigin, OnUnbalanced};
 use frame_support::weights::Weight;
 use frame_support::{
     dispatch::DispatchResult,
@@ -28,164 +34,1002 @@ use frame_support::{
     Blake2_128Concat, BoundedVec,
 };
 use scale_info::TypeInfo;
-use sp_runtime::traits::Hash;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::traits::{Convert, Hash, One, Saturating, Zero};
+use sp_runtime::{PerThing, Perbill, RuntimeDebug};
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::prelude::*;
+use traits::{ArtistCollectionFactory, ArtistTokenFactory, IdentityProvider, ReputationPolicy};
 
 pub use pallet::*;
 
-/// Origin for the collective module.
+/// Origin for the artists module.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(I))]
 #[codec(mel_bound(AccountId: MaxEncodedLen))]
-pub enum RawOrigin<AccountId> {
+pub enum RawOrigin<AccountId, I> {
     /// It has been condoned by a single artist.
     Artist(AccountId),
     /// It has been condoned by a single Candidate.
     Candidate(AccountId),
+    /// Dummy to manage the fact we have instancing.
+    _Phantom(PhantomData<I>),
 }
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use crate::weights::WeightInfo;
-    use allfeat_support::types::actors::artist::{ArtistData, CandidateData};
+    use allfeat_support::types::actors::artist::CandidateData;
     use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
     use frame_support::pallet_prelude::*;
+    use frame_support::traits::IsSubType;
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::Dispatchable;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
-    pub struct Pallet<T>(PhantomData<T>);
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>: frame_system::Config {
         /// Let the pallet to emit events
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// Used for candidate/artist deposit
         type Currency: ReservableCurrency<Self::AccountId>;
 
+        /// What to do with slashed funds.
+        type Slashed: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+
         /// The outer origin type.
-        type Origin: From<RawOrigin<Self::AccountId>>;
+        type Origin: From<RawOrigin<Self::AccountId, I>>
+            + From<<Self as frame_system::Config>::RuntimeOrigin>
+            + Into<Result<RawOrigin<Self::AccountId, I>, <Self as Config<I>>::Origin>>;
 
         /// Who can certificate an Artist
         type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+        /// Who can veto a scheduled approval, in addition to `AdminOrigin`.
+        type VetoOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
         type Call: Parameter
-            + Dispatchable<RuntimeOrigin = <Self as Config>::Origin, PostInfo = PostDispatchInfo>
+            + Dispatchable<RuntimeOrigin = <Self as Config<I>>::Origin, PostInfo = PostDispatchInfo>
             + From<frame_system::Call<Self>>
+            + From<Call<Self, I>>
+            + IsSubType<Call<Self, I>>
             + GetDispatchInfo;
 
         /// The deposit needed for creating an artist account.
         #[pallet::constant]
-        type CreationDepositAmount: Get<BalanceOf<Self>>;
+        type CreationDepositAmount: Get<BalanceOf<Self, I>>;
+
+        /// The deposit held for each evidence attached to a candidacy.
+        #[pallet::constant]
+        type EvidenceDeposit: Get<BalanceOf<Self, I>>;
+
+        /// The maximum number of evidence items attached to a candidacy.
+        #[pallet::constant]
+        type MaxEvidenceItems: Get<u32>;
+
+        /// The maximum length of a link or statement given as evidence.
+        #[pallet::constant]
+        type EvidenceMaxLength: Get<u32>;
+
+        /// The additional deposit held from an artist for its verification level,
+        /// on top of `CreationDepositAmount`.
+        type LevelDeposit: Convert<VerificationLevel, BalanceOf<Self, I>>;
 
         /// The maximum length of an artist name or symbol stored on-chain.
         #[pallet::constant]
         type NameMaxLength: Get<u32>;
 
+        /// The maximum length of the metadata of an artist.
+        #[pallet::constant]
+        type MetadataMaxLength: Get<u32>;
+
+        /// The maximum number of registered artists, suspended ones included.
+        #[pallet::constant]
+        type MaxArtists: Get<u32>;
+
+        /// The maximum number of pending candidacies.
+        #[pallet::constant]
+        type MaxCandidates: Get<u32>;
+
+        /// The maximum number of verifiers allowed to attest candidacies.
+        #[pallet::constant]
+        type MaxVerifiers: Get<u32>;
+
+        /// The number of distinct verifiers approvals needed to promote a candidate.
+        #[pallet::constant]
+        type AttestationQuorum: Get<u32>;
+
+        /// The maximum number of artists endorsing a candidate.
+        #[pallet::constant]
+        type MaxEndorsements: Get<u32>;
+
+        /// The number of endorsements approving a candidate, if any.
+        #[pallet::constant]
+        type EndorsementThreshold: Get<Option<u32>>;
+
+        /// The maximum number of calls a candidate could dispatch through
+        /// `call_as_candidate` per `CandidateEraLength`.
+        #[pallet::constant]
+        type MaxCandidateCalls: Get<u32>;
+
+        /// The maximum weight a candidate could dispatch through `call_as_candidate`
+        /// per `CandidateEraLength`.
+        #[pallet::constant]
+        type MaxCandidateCallWeight: Get<Weight>;
+
+        /// The number of blocks after which the call budget of a candidate is reset.
+        #[pallet::constant]
+        type CandidateEraLength: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of fee-less candidacy submissions and withdrawals
+        /// of an account per `FreeCallPeriod`, see [`CheckFreeCandidacy`].
+        #[pallet::constant]
+        type MaxFreeCalls: Get<u32>;
+
+        /// The number of blocks after which the fee-less calls of an account are reset.
+        #[pallet::constant]
+        type FreeCallPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The number of blocks between the approval of a candidate and its enactment,
+        /// during which the approval could be vetoed.
+        #[pallet::constant]
+        type ApprovalDelay: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of approvals enacted at the same block.
+        #[pallet::constant]
+        type MaxApprovalsPerBlock: Get<u32>;
+
+        /// The maximum number of suspensions ending at the same block.
+        #[pallet::constant]
+        type MaxSuspensionsPerBlock: Get<u32>;
+
+        /// The bond held from an account challenging an artist.
+        #[pallet::constant]
+        type ChallengeBond: Get<BalanceOf<Self, I>>;
+
+        /// The part of the deposit of a revoked artist awarded to the challenger.
+        #[pallet::constant]
+        type ChallengerReward: Get<Perbill>;
+
+        /// The number of blocks after which an unresolved dispute could be closed.
+        #[pallet::constant]
+        type DisputeTimeout: Get<BlockNumberFor<Self>>;
+
+        /// The deposit held for each work registered in the catalog of an artist.
+        #[pallet::constant]
+        type WorkDeposit: Get<BalanceOf<Self, I>>;
+
+        /// The maximum number of works in the catalog of an artist.
+        #[pallet::constant]
+        type MaxWorksPerArtist: Get<u32>;
+
+        /// The maximum length of the title of a work.
+        #[pallet::constant]
+        type TitleMaxLength: Get<u32>;
+
+        /// The deposit held for each version of a royalty split.
+        #[pallet::constant]
+        type SplitDeposit: Get<BalanceOf<Self, I>>;
+
+        /// The maximum number of payees of a royalty split.
+        #[pallet::constant]
+        type MaxSplitPayees: Get<u32>;
+
+        /// The maximum number of royalty splits owned by an artist.
+        #[pallet::constant]
+        type MaxSplitsPerArtist: Get<u32>;
+
+        /// The maximum number of versions of a royalty split.
+        #[pallet::constant]
+        type MaxSplitVersions: Get<u32>;
+
+        /// The deposit held from an artist proposing a collaboration, until the
+        /// collaboration is confirmed or cancelled.
+        #[pallet::constant]
+        type CollaborationDeposit: Get<BalanceOf<Self, I>>;
+
+        /// The maximum number of confirmed collaborations of an artist.
+        #[pallet::constant]
+        type MaxCollaborations: Get<u32>;
+
+        /// The maximum number of proposals waiting for a confirmation, proposed by or to
+        /// an artist.
+        #[pallet::constant]
+        type MaxPendingCollaborations: Get<u32>;
+
+        /// The deposit held from an account for each artist it follows.
+        #[pallet::constant]
+        type FollowDeposit: Get<BalanceOf<Self, I>>;
+
+        /// The maximum number of follows released at once when an artist leaves, the
+        /// remaining ones being released through `reap_followers`.
+        #[pallet::constant]
+        type MaxFollowersReaped: Get<u32>;
+
+        /// The registry issuing the fan tokens of the artists, e.g. `FungibleTokens` over
+        /// `pallet_assets`, `()` to disable them.
+        type Assets: ArtistTokenFactory<Self::AccountId>;
+
+        /// The asset identifier of the fan token issued at a given index, `None` to disable
+        /// the fan tokens. The identifiers must be reserved to the pallet, e.g. through the
+        /// `CreateOrigin` of the registry, so that no other account could take them first.
+        type FanTokenId: Convert<FanTokenIndex, Option<AssetIdOf<Self, I>>>;
+
+        /// The deposit held from an artist issuing its fan token, for as long as the
+        /// artist is registered.
+        #[pallet::constant]
+        type FanTokenDeposit: Get<BalanceOf<Self, I>>;
+
+        /// The factory of the verified NFT collections of the artists, `()` to disable
+        /// the collections.
+        type Collections: ArtistCollectionFactory<Self::AccountId>;
+
+        /// The on-chain identities checked against the identity policy of the candidacies,
+        /// `()` when there is none.
+        type Identity: IdentityProvider<Self::AccountId>;
+
+        /// How the reputation of the artists is scored.
+        type ReputationPolicy: ReputationPolicy<BlockNumberFor<Self>>;
+
+        /// The receiver of the signal for when the artist set has changed.
+        type MembershipChanged: ChangeMembers<Self::AccountId>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
 
     #[pallet::origin]
-    pub type Origin<T> = RawOrigin<<T as frame_system::Config>::AccountId>;
+    pub type Origin<T, I = ()> = RawOrigin<<T as frame_system::Config>::AccountId, I>;
 
     #[pallet::storage]
     #[pallet::getter(fn get_candidate)]
-    pub(super) type Candidates<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, CandidateOf<T>, OptionQuery>;
+    pub(super) type Candidates<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CandidateOf<T, I>, OptionQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn get_artist)]
-    pub(super) type Artists<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, ArtistOf<T>, OptionQuery>;
+    pub(super) type Artists<T: Config<I>, I: 'static = ()> =
+        CountedStorageMap<_, Blake2_128Concat, T::AccountId, ArtistOf<T, I>, OptionQuery>;
+
+    /// The accounts of the active artists, sorted, as signaled to `T::MembershipChanged`.
+    #[pallet::storage]
+    #[pallet::getter(fn active_artists)]
+    pub(super) type SortedArtists<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxArtists>, ValueQuery>;
+
+    /// The accounts of the current candidates, sorted.
+    #[pallet::storage]
+    #[pallet::getter(fn current_candidates)]
+    pub(super) type SortedCandidates<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxCandidates>, ValueQuery>;
+
+    /// The account of an artist, by artist identifier.
+    #[pallet::storage]
+    #[pallet::getter(fn get_artist_account)]
+    pub(super) type ArtistAccounts<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, ArtistId, T::AccountId, OptionQuery>;
+
+    /// The identifier given to the next artist.
+    #[pallet::storage]
+    #[pallet::getter(fn next_artist_id)]
+    pub(super) type NextArtistId<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, ArtistId, ValueQuery>;
+
+    /// The index of the next fan token, converted by `T::FanTokenId` to its asset identifier.
+    #[pallet::storage]
+    #[pallet::getter(fn next_fan_token)]
+    pub(super) type NextFanToken<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, FanTokenIndex, ValueQuery>;
+
+    /// The sponsor holding the creation deposit of a candidate or an artist,
+    /// when it isn't held by the account itself.
+    #[pallet::storage]
+    #[pallet::getter(fn get_sponsor)]
+    pub(super) type Sponsors<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// The creation deposit held for a candidacy, from the candidate or its sponsor.
+    #[pallet::storage]
+    #[pallet::getter(fn get_candidacy_deposit)]
+    pub(super) type CandidacyDeposits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>, OptionQuery>;
+
+    /// The evidence attached to a candidacy, with the deposit held for each item.
+    #[pallet::storage]
+    #[pallet::getter(fn get_candidacy_evidence)]
+    pub(super) type CandidacyEvidence<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(EvidenceOf<T, I>, BalanceOf<T, I>), T::MaxEvidenceItems>,
+        ValueQuery,
+    >;
+
+    /// The artists vouching for a candidate.
+    #[pallet::storage]
+    #[pallet::getter(fn get_endorsements)]
+    pub(super) type Endorsements<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxEndorsements>,
+        ValueQuery,
+    >;
+
+    /// The calls dispatched by a candidate through `call_as_candidate` in its current era.
+    #[pallet::storage]
+    #[pallet::getter(fn get_candidate_call_usage)]
+    pub(super) type CandidateCallUsage<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CallUsage<BlockNumberFor<T>>, OptionQuery>;
+
+    /// The fee-less calls made by an account, with the block starting the current period.
+    #[pallet::storage]
+    #[pallet::getter(fn get_free_calls)]
+    pub(super) type FreeCalls<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32), OptionQuery>;
+
+    /// The approved candidates waiting for the enactment of their approval, with the
+    /// block of the enactment and what led to the approval.
+    #[pallet::storage]
+    #[pallet::getter(fn get_pending_approval)]
+    pub(super) type PendingApprovals<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (BlockNumberFor<T>, ApprovalSource),
+        OptionQuery,
+    >;
+
+    /// The approved candidates to level up, by block of enactment.
+    #[pallet::storage]
+    #[pallet::getter(fn get_approval_queue)]
+    pub(super) type ApprovalQueue<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::AccountId, T::MaxApprovalsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The suspended artists to reinstate, by block of the end of their suspension.
+    #[pallet::storage]
+    #[pallet::getter(fn get_suspension_expiries)]
+    pub(super) type SuspensionExpiries<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::AccountId, T::MaxSuspensionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The challenges opened against artists, by challenged artist.
+    #[pallet::storage]
+    #[pallet::getter(fn get_dispute)]
+    pub(super) type Disputes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, DisputeOf<T, I>, OptionQuery>;
+
+    /// The accounts allowed to attest candidacies, with the fee they charge for it.
+    #[pallet::storage]
+    #[pallet::getter(fn get_verifier_fee)]
+    pub(super) type Verifiers<T: Config<I>, I: 'static = ()> =
+        CountedStorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>, OptionQuery>;
+
+    /// The attestation fees held from a candidate, by requested verifier.
+    #[pallet::storage]
+    #[pallet::getter(fn get_attestation_request)]
+    pub(super) type AttestationRequests<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T, I>,
+        OptionQuery,
+    >;
+
+    /// The verdicts given on a candidate, by verifier.
+    #[pallet::storage]
+    #[pallet::getter(fn get_attestation)]
+    pub(super) type Attestations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        Verdict,
+        OptionQuery,
+    >;
+
+    /// The works registered by an artist, with the deposit held for each work.
+    #[pallet::storage]
+    #[pallet::getter(fn get_catalog)]
+    pub(super) type Catalog<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(WorkOf<T, I>, BalanceOf<T, I>), T::MaxWorksPerArtist>,
+        ValueQuery,
+    >;
+
+    /// The artist having registered a work, by ISRC of the work.
+    #[pallet::storage]
+    #[pallet::getter(fn get_isrc_owner)]
+    pub(super) type WorksByIsrc<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, Isrc, T::AccountId, OptionQuery>;
+
+    /// The royalty splits defined by the artists.
+    #[pallet::storage]
+    #[pallet::getter(fn get_royalty_split)]
+    pub(super) type RoyaltySplits<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, SplitId, RoyaltySplitOf<T, I>, OptionQuery>;
+
+    /// The royalty splits owned by an artist, by order of creation.
+    #[pallet::storage]
+    #[pallet::getter(fn get_artist_splits)]
+    pub(super) type ArtistSplits<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<SplitId, T::MaxSplitsPerArtist>,
+        ValueQuery,
+    >;
+
+    /// The payees of every version of the royalty splits, with their shares.
+    #[pallet::storage]
+    #[pallet::getter(fn get_split_version)]
+    pub(super) type SplitVersions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SplitId,
+        Twox64Concat,
+        SplitVersion,
+        SharesOf<T, I>,
+        OptionQuery,
+    >;
+
+    /// The identifier given to the next royalty split.
+    #[pallet::storage]
+    #[pallet::getter(fn next_split_id)]
+    pub(super) type NextSplitId<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, SplitId, ValueQuery>;
+
+    /// The collaborations between artists, confirmed or waiting for the counterparty.
+    #[pallet::storage]
+    #[pallet::getter(fn get_collaboration)]
+    pub(super) type Collaborations<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, CollaborationId, CollaborationOf<T, I>, OptionQuery>;
+
+    /// The identifier given to the next proposed collaboration.
+    #[pallet::storage]
+    #[pallet::getter(fn next_collaboration_id)]
+    pub(super) type NextCollaborationId<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, CollaborationId, ValueQuery>;
+
+    /// The confirmed collaborations of an artist, by order of confirmation.
+    #[pallet::storage]
+    #[pallet::getter(fn get_artist_collaborations)]
+    pub(super) type ArtistCollaborations<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<CollaborationId, T::MaxCollaborations>,
+        ValueQuery,
+    >;
+
+    /// The proposals waiting for a confirmation, listed for both the proposer and the
+    /// counterparty.
+    #[pallet::storage]
+    #[pallet::getter(fn get_pending_collaborations)]
+    pub(super) type PendingCollaborations<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<CollaborationId, T::MaxPendingCollaborations>,
+        ValueQuery,
+    >;
+
+    /// The identity requirements of the candidacies, set by `T::AdminOrigin`.
+    #[pallet::storage]
+    #[pallet::getter(fn identity_policy)]
+    pub(super) type CandidateIdentityPolicy<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, IdentityPolicy, ValueQuery>;
+
+    /// The accounts following an artist with the deposit held for the follow.
+    #[pallet::storage]
+    #[pallet::getter(fn get_follow)]
+    pub(super) type Followers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ArtistId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T, I>,
+        OptionQuery,
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::lift_expired_suspensions(n).saturating_add(Self::enact_scheduled_approvals(n))
+        }
+    }
 
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
-    pub struct GenesisConfig<T: Config> {
+    pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+        #[serde(skip)]
+        pub phantom: PhantomData<I>,
         /// The existing artists at the genesis
-        pub artists: Vec<(T::AccountId, Vec<u8>)>,
+        pub artists: Vec<GenesisArtist<T::AccountId>>,
         /// The existing candidates at the genesis
         pub candidates: Vec<(T::AccountId, Vec<u8>)>,
     }
 
     #[pallet::genesis_build]
-    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+    impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
         fn build(&self) {
-            for (account_id, name) in &self.artists {
-                let name: BoundedVec<u8, T::NameMaxLength> = name
-                    .clone()
-                    .try_into()
-                    .expect("Error while formatting the artist name");
-
-                if Artists::<T>::contains_key(&account_id) {
-                    panic!("Artist already added to the list")
-                }
-
-                T::Currency::reserve(&account_id, T::CreationDepositAmount::get())
-                    .expect("Could not reverse deposit for the candidate");
+            if let Err(error) = self.validate() {
+                panic!("Invalid artists genesis config: {:?}", error);
+            }
 
-                let artist = ArtistData {
-                    name,
-                    created_at: <frame_system::Pallet<T>>::block_number(),
+            let now = <frame_system::Pallet<T>>::block_number();
+
+            // The artists without identifier get the ones following the highest given
+            let mut next_id = self
+                .artists
+                .iter()
+                .filter_map(|artist| artist.id)
+                .max()
+                .map_or(0, |id| id.saturating_add(1));
+
+            for artist in &self.artists {
+                let deposit = Self::deposit_of(artist);
+                T::Currency::reserve(&artist.account, deposit)
+                    .expect("Balance checked by the validation; qed");
+
+                let id = artist.id.unwrap_or_else(|| {
+                    let id = next_id;
+                    next_id = next_id.saturating_add(1);
+                    id
+                });
+
+                let record = ArtistRecord {
+                    id,
+                    name: artist
+                        .name
+                        .clone()
+                        .try_into()
+                        .expect("Length checked by the validation; qed"),
+                    metadata: artist
+                        .metadata
+                        .clone()
+                        .try_into()
+                        .expect("Length checked by the validation; qed"),
+                    created_at: now,
+                    level: artist.level,
+                    deposit,
+                    deposit_exempt: artist.deposit_exempt,
+                    fan_token: None,
+                    collection: None,
+                    followers: 0,
+                    reputation: T::ReputationPolicy::reputation(&Default::default(), Zero::zero()),
+                    reputation_stats: Default::default(),
+                    status: ArtistStatus::Active,
                 };
 
-                Artists::<T>::insert(&account_id, artist);
+                ArtistAccounts::<T, I>::insert(id, &artist.account);
+                Artists::<T, I>::insert(&artist.account, record);
             }
+            NextArtistId::<T, I>::put(next_id);
 
-            for (account_id, name) in &self.candidates {
-                let name: BoundedVec<u8, T::NameMaxLength> = name
-                    .clone()
-                    .try_into()
-                    .expect("Error while formatting the candidate name");
-
-                if Candidates::<T>::contains_key(&account_id) {
-                    panic!("Candidate already added to the list")
-                }
+            let accounts: Vec<T::AccountId> = self
+                .artists
+                .iter()
+                .map(|artist| artist.account.clone())
+                .collect();
+            // Bounded by `T::MaxArtists`, checked by the validation
+            Pallet::<T, I>::notify_artists_changed(&accounts, &[]);
 
-                T::Currency::reserve(&account_id, T::CreationDepositAmount::get())
-                    .expect("Could not reverse deposit for the candidate");
+            for (account_id, name) in &self.candidates {
+                T::Currency::reserve(account_id, T::CreationDepositAmount::get())
+                    .expect("Balance checked by the validation; qed");
+                CandidacyDeposits::<T, I>::insert(account_id, T::CreationDepositAmount::get());
 
                 let candidate = CandidateData {
-                    name,
-                    created_at: <frame_system::Pallet<T>>::block_number(),
+                    name: name
+                        .clone()
+                        .try_into()
+                        .expect("Length checked by the validation; qed"),
+                    created_at: now,
                 };
 
-                Candidates::<T>::insert(&account_id, candidate);
+                Candidates::<T, I>::insert(account_id, candidate);
+            }
+
+            let mut candidates: Vec<T::AccountId> = self
+                .candidates
+                .iter()
+                .map(|(account_id, _)| account_id.clone())
+                .collect();
+            candidates.sort();
+            SortedCandidates::<T, I>::put(
+                BoundedVec::try_from(candidates).expect("Bounded by the validation; qed"),
+            );
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> GenesisConfig<T, I> {
+        /// Check that the config could be built, reporting the first issue found.
+        ///
+        /// The balances are read from the state, the config should then be validated
+        /// once the balances are set.
+        pub fn validate(&self) -> Result<(), GenesisError<T::AccountId>> {
+            ensure!(
+                self.artists.len() <= T::MaxArtists::get() as usize,
+                GenesisError::TooManyArtists
+            );
+            ensure!(
+                self.candidates.len() <= T::MaxCandidates::get() as usize,
+                GenesisError::TooManyCandidates
+            );
+
+            let mut artists = BTreeSet::new();
+            let mut ids = BTreeSet::new();
+            for artist in &self.artists {
+                let account = &artist.account;
+                ensure!(
+                    artists.insert(account.clone()),
+                    GenesisError::DuplicateArtist(account.clone())
+                );
+                if let Some(id) = artist.id {
+                    ensure!(ids.insert(id), GenesisError::DuplicateArtistId(id));
+                }
+                ensure!(
+                    artist.name.len() <= T::NameMaxLength::get() as usize,
+                    GenesisError::NameTooLong(account.clone())
+                );
+                ensure!(
+                    artist.metadata.len() <= T::MetadataMaxLength::get() as usize,
+                    GenesisError::MetadataTooLong(account.clone())
+                );
+                ensure!(
+                    T::Currency::can_reserve(account, Self::deposit_of(artist)),
+                    GenesisError::InsufficientBalance(account.clone())
+                );
+            }
+
+            let mut candidates = BTreeSet::new();
+            for (account, name) in &self.candidates {
+                ensure!(
+                    !artists.contains(account),
+                    GenesisError::ArtistAndCandidate(account.clone())
+                );
+                ensure!(
+                    candidates.insert(account.clone()),
+                    GenesisError::DuplicateCandidate(account.clone())
+                );
+                ensure!(
+                    name.len() <= T::NameMaxLength::get() as usize,
+                    GenesisError::NameTooLong(account.clone())
+                );
+                ensure!(
+                    T::Currency::can_reserve(account, T::CreationDepositAmount::get()),
+                    GenesisError::InsufficientBalance(account.clone())
+                );
+            }
+            Ok(())
+        }
+
+        /// The deposit held for an artist registered at genesis.
+        fn deposit_of(artist: &GenesisArtist<T::AccountId>) -> BalanceOf<T, I> {
+            if artist.deposit_exempt {
+                Zero::zero()
+            } else {
+                T::CreationDepositAmount::get()
+                    .saturating_add(T::LevelDeposit::convert(artist.level))
             }
         }
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         // Candidate events:
         // =================
-        /// An entity has issued a candidacy. See the transaction for who.
-        CandidateAdded(T::AccountId),
-        /// An entity withdrew candidacy. See the transaction for who.
-        CandidateWithdrew(T::AccountId),
-        /// An artist was created from a candidate after approbation.
+        /// An entity has issued a candidacy, the creation deposit is held.
+        CandidateAdded {
+            candidate: T::AccountId,
+            name: BoundedVec<u8, T::NameMaxLength>,
+            deposit: BalanceOf<T, I>,
+            created_at: BlockNumberFor<T>,
+        },
+        /// A candidacy was submitted on behalf of the candidate, the creation deposit
+        /// is held from the sponsor.
+        CandidateSponsored {
+            candidate: T::AccountId,
+            sponsor: T::AccountId,
+            deposit: BalanceOf<T, I>,
+        },
+        /// A candidate or an artist took over its creation deposit, the sponsor got it back.
+        SponsorshipEnded {
+            who: T::AccountId,
+            sponsor: T::AccountId,
+            deposit: BalanceOf<T, I>,
+        },
+        /// An entity withdrew candidacy, the creation deposit is released to the
+        /// depositor, the candidate or its sponsor.
+        CandidateWithdrew {
+            candidate: T::AccountId,
+            depositor: T::AccountId,
+            deposit: BalanceOf<T, I>,
+        },
+        /// A candidacy was rejected by the admin and the deposits were released, the
+        /// creation deposit to the depositor, the candidate or its sponsor.
+        CandidateRejected {
+            candidate: T::AccountId,
+            depositor: T::AccountId,
+            deposit: BalanceOf<T, I>,
+        },
+        /// A candidate attached an evidence to its candidacy, the deposit is held.
+        CandidacyEvidenceAdded {
+            candidate: T::AccountId,
+            evidence: EvidenceOf<T, I>,
+            deposit: BalanceOf<T, I>,
+        },
+        /// An artist vouched for a candidate.
+        CandidateEndorsed {
+            candidate: T::AccountId,
+            artist: T::AccountId,
+            endorsements: u32,
+        },
+        /// The approval of a candidate was scheduled, it could be vetoed until its enactment.
+        ApprovalScheduled {
+            candidate: T::AccountId,
+            enact_at: BlockNumberFor<T>,
+            source: ApprovalSource,
+        },
+        /// A scheduled approval was enacted and the candidate leveled up as an artist.
+        ApprovalEnacted { candidate: T::AccountId },
+        /// An approval could not be scheduled after reaching a threshold, or could not
+        /// be enacted.
+        ApprovalFailed {
+            candidate: T::AccountId,
+            error: DispatchError,
+        },
+        /// A scheduled approval was vetoed, the account stays a candidate.
+        ApprovalVetoed { candidate: T::AccountId },
+        /// An artist was created from a candidate after approbation, the level deposit is
+        /// held on top of the creation deposit.
         /// This artist is also added to the artist membership
-        CandidateApproved(T::AccountId),
+        CandidateApproved {
+            candidate: T::AccountId,
+            name: BoundedVec<u8, T::NameMaxLength>,
+            deposit: BalanceOf<T, I>,
+            source: ApprovalSource,
+        },
         /// A Candidate called an extrinsic
         CandidateExecuted {
             dispatch_hash: T::Hash,
             result: DispatchResult,
         },
 
+        /// A candidate asked a verifier to attest its candidacy, the fee is held.
+        AttestationRequested {
+            candidate: T::AccountId,
+            verifier: T::AccountId,
+            fee: BalanceOf<T, I>,
+        },
+        /// A candidate cancelled an attestation request, the fee is released.
+        AttestationRequestCancelled {
+            candidate: T::AccountId,
+            verifier: T::AccountId,
+        },
+        /// A verifier gave its verdict on a candidate and got paid the held fee.
+        CandidacyAttested {
+            candidate: T::AccountId,
+            verifier: T::AccountId,
+            verdict: Verdict,
+        },
+
+        // Verifier events:
+        // ================
+        /// A verifier was added with the given fee.
+        VerifierAdded {
+            verifier: T::AccountId,
+            fee: BalanceOf<T, I>,
+        },
+        /// A verifier was removed.
+        VerifierRemoved { verifier: T::AccountId },
+        /// A verifier changed its fee.
+        VerifierFeeSet {
+            verifier: T::AccountId,
+            fee: BalanceOf<T, I>,
+        },
+
         // Artist events:
         // ==============
+        /// The verification level of an artist changed, the level deposit was adjusted.
+        /// `verifier` is `None` when the change was made by the admin.
+        VerificationLevelChanged {
+            artist: T::AccountId,
+            previous: VerificationLevel,
+            level: VerificationLevel,
+            deposit: BalanceOf<T, I>,
+            verifier: Option<T::AccountId>,
+        },
+        /// An artist was challenged, the challenger bond is held.
+        ArtistChallenged {
+            artist: T::AccountId,
+            challenger: T::AccountId,
+            evidence_hash: T::Hash,
+            bond: BalanceOf<T, I>,
+        },
+        /// A challenge was upheld, the artist is revoked and part of its deposit
+        /// awarded to the challenger.
+        DisputeUpheld {
+            artist: T::AccountId,
+            challenger: T::AccountId,
+            reward: BalanceOf<T, I>,
+        },
+        /// A challenge was dismissed and the challenger bond slashed.
+        DisputeDismissed {
+            artist: T::AccountId,
+            challenger: T::AccountId,
+            slashed: BalanceOf<T, I>,
+        },
+        /// A challenge expired without resolution, the challenger bond is released.
+        DisputeExpired {
+            artist: T::AccountId,
+            challenger: T::AccountId,
+        },
+        /// An artist was suspended and is treated as inactive until the given block.
+        ArtistSuspended {
+            artist: T::AccountId,
+            until: BlockNumberFor<T>,
+            reason: SuspensionReason,
+        },
+        /// The suspension of an artist was lifted, `early` when the admin lifted it
+        /// before its end.
+        ArtistUnsuspended {
+            artist: T::AccountId,
+            until: BlockNumberFor<T>,
+            early: bool,
+        },
+        /// An artist was registered by the admin without candidacy, `deposit_exempt`
+        /// when no deposit is held from it.
+        ArtistRegistered {
+            artist: T::AccountId,
+            id: ArtistId,
+            name: BoundedVec<u8, T::NameMaxLength>,
+            deposit: BalanceOf<T, I>,
+            deposit_exempt: bool,
+        },
+        /// An artist issued its fan token, the supply is minted to the artist and the
+        /// deposit is held.
+        FanTokenCreated {
+            artist: T::AccountId,
+            asset: AssetIdOf<T, I>,
+            supply: AssetBalanceOf<T, I>,
+            deposit: BalanceOf<T, I>,
+        },
+        /// The verified NFT collection of an artist was created.
+        CollectionCreated {
+            artist: T::AccountId,
+            collection: CollectionIdOf<T, I>,
+        },
+        /// The identity policy of the candidacies was changed.
+        IdentityPolicySet { policy: IdentityPolicy },
+        /// An account followed an artist.
+        ArtistFollowed {
+            artist_id: ArtistId,
+            follower: T::AccountId,
+        },
+        /// An account stopped following an artist.
+        ArtistUnfollowed {
+            artist_id: ArtistId,
+            follower: T::AccountId,
+        },
+        /// The follows of an artist who left were released.
+        FollowersReaped { artist_id: ArtistId, count: u32 },
+        /// The reputation of an artist changed.
+        ReputationChanged {
+            artist: T::AccountId,
+            previous: Reputation,
+            reputation: Reputation,
+        },
+        /// An artist was revoked and removed from the artist membership.
+        ArtistRevoked {
+            artist: T::AccountId,
+            name: BoundedVec<u8, T::NameMaxLength>,
+            deposit: BalanceOf<T, I>,
+            previous_status: ArtistStatus<BlockNumberFor<T>>,
+        },
         /// An Artist called an extrinsic
         ArtistExecuted {
             dispatch_hash: T::Hash,
             result: DispatchResult,
         },
+
+        // Catalog events:
+        // ===============
+        /// An artist registered a work in its catalog, the deposit is held.
+        WorkRegistered {
+            artist: T::AccountId,
+            isrc: Isrc,
+            content_hash: T::Hash,
+            deposit: BalanceOf<T, I>,
+        },
+        /// An artist updated a work of its catalog.
+        WorkUpdated { artist: T::AccountId, isrc: Isrc },
+        /// A work was removed from the catalog of an artist, the deposit is released.
+        WorkRetired {
+            artist: T::AccountId,
+            isrc: Isrc,
+            deposit: BalanceOf<T, I>,
+        },
+
+        // Royalty events:
+        // ===============
+        /// An artist defined a royalty split, the deposit of the first version is held.
+        SplitCreated {
+            id: SplitId,
+            owner: T::AccountId,
+            deposit: BalanceOf<T, I>,
+        },
+        /// A new version of a royalty split is active, the deposit of the version is held.
+        SplitUpdated {
+            id: SplitId,
+            version: SplitVersion,
+            deposit: BalanceOf<T, I>,
+        },
+        /// A royalty split was locked, its active version is final.
+        SplitLocked { id: SplitId, version: SplitVersion },
+        /// A royalty split was retired by its owner or because the artist was revoked.
+        /// Its versions are removed and their deposits released.
+        SplitRemoved {
+            id: SplitId,
+            deposit: BalanceOf<T, I>,
+        },
+
+        // Collaboration events:
+        // =====================
+        /// An artist proposed a collaboration to another artist, the deposit is held.
+        CollaborationProposed {
+            id: CollaborationId,
+            proposer: T::AccountId,
+            counterparty: T::AccountId,
+            work_hash: T::Hash,
+        },
+        /// The counterparty confirmed a collaboration, the deposit is released.
+        CollaborationConfirmed {
+            id: CollaborationId,
+            proposer: T::AccountId,
+            counterparty: T::AccountId,
+        },
+        /// A proposed collaboration was cancelled by either artist, or because one of them
+        /// was revoked. The deposit is released.
+        CollaborationCancelled {
+            id: CollaborationId,
+            cancelled_by: T::AccountId,
+        },
+        /// A confirmed collaboration was dissolved by either artist, or because one of them
+        /// was revoked. It is no longer listed for the artists.
+        CollaborationDissolved {
+            id: CollaborationId,
+            dissolved_by: T::AccountId,
+        },
     }
 
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         // General errors:
         // ===============
         /// The caller doesn't have enough funds for the deposit
@@ -201,6 +1045,22 @@ pub mod pallet {
         CandidateNotFound,
         /// The caller isn't in the candidate list.
         NotACandidate,
+        /// The creation deposit of this account isn't held by a sponsor.
+        NotSponsored,
+        /// The maximum number of evidence items is reached for this candidacy.
+        TooManyEvidenceItems,
+        /// The candidate exhausted its call budget for the current era.
+        CandidateRateLimited,
+        /// The approval of this candidate is already scheduled.
+        ApprovalAlreadyScheduled,
+        /// No approval is scheduled for this candidate.
+        ApprovalNotScheduled,
+        /// The maximum number of approvals enacted at the same block is reached.
+        TooManyScheduledApprovals,
+        /// The artist already endorsed this candidate.
+        AlreadyEndorsed,
+        /// The maximum number of endorsements is reached for this candidate.
+        TooManyEndorsements,
 
         // Artist related errors:
         // ======================
@@ -210,10 +1070,113 @@ pub mod pallet {
         NotAnArtist,
         /// The wanted artist is not found in the Artists Storage
         ArtistNotFound,
+        /// The maximum number of registered artists is reached.
+        TooManyArtists,
+        /// The maximum number of pending candidacies is reached.
+        TooManyCandidates,
+        /// The artist already has the wanted verification level.
+        SameVerificationLevel,
+        /// The artist is suspended.
+        ArtistSuspended,
+        /// The artist isn't suspended.
+        ArtistNotSuspended,
+        /// The end of the suspension must be in the future.
+        InvalidSuspensionEnd,
+        /// The maximum number of suspensions ending at this block is reached.
+        TooManySuspensions,
+        /// The artist already issued its fan token.
+        FanTokenAlreadyCreated,
+        /// The fan tokens are disabled in this registry.
+        FanTokensDisabled,
+        /// The artist already has a verified collection.
+        CollectionAlreadyCreated,
+        /// The account lacks the identity judgement required by the identity policy.
+        IdentityJudgementRequired,
+        /// The account already follows this artist.
+        AlreadyFollowing,
+        /// The account doesn't follow this artist.
+        NotFollowing,
+        /// The followers of a registered artist can't be reaped.
+        ArtistStillRegistered,
+        /// There are no followers left to reap.
+        NoFollowers,
+
+        // Dispute related errors:
+        // =======================
+        /// A challenge is already opened against this artist.
+        DisputeAlreadyOpened,
+        /// No challenge is opened against this artist.
+        DisputeNotFound,
+        /// The dispute could still be resolved by the admin.
+        DisputeNotExpired,
+
+        // Catalog related errors:
+        // ========================
+        /// The ISRC or the ISWC of the work is malformed.
+        InvalidWorkCode,
+        /// A work with this ISRC is already registered.
+        WorkAlreadyRegistered,
+        /// The wanted work is not found in the catalog of the caller.
+        WorkNotFound,
+        /// The maximum number of works is reached for this artist.
+        TooManyWorks,
+
+        // Royalty related errors:
+        // ========================
+        /// The shares of the split don't sum to 100%.
+        InvalidSplitShares,
+        /// A payee appears more than once in the split.
+        DuplicatePayee,
+        /// The wanted split is not found in the RoyaltySplits Storage
+        SplitNotFound,
+        /// The caller isn't the owner of the split.
+        NotSplitOwner,
+        /// The split is locked and could no longer be updated.
+        SplitLocked,
+        /// The maximum number of royalty splits is reached for the artist.
+        TooManySplits,
+        /// The maximum number of versions is reached for the split.
+        TooManySplitVersions,
+
+        // Collaboration related errors:
+        // =============================
+        /// An artist can't collaborate with itself.
+        SelfCollaboration,
+        /// The wanted collaboration is not found in the Collaborations Storage
+        CollaborationNotFound,
+        /// The collaboration is already confirmed.
+        CollaborationAlreadyConfirmed,
+        /// The collaboration isn't confirmed yet, it may only be cancelled.
+        CollaborationNotConfirmed,
+        /// The caller isn't part of the collaboration or may not act on it.
+        NotACollaborator,
+        /// The maximum number of confirmed collaborations is reached for an artist.
+        TooManyCollaborations,
+        /// The maximum number of pending proposals is reached for an artist.
+        TooManyPendingCollaborations,
+
+        // Verifier related errors:
+        // ========================
+        /// The account already is a verifier.
+        AlreadyAVerifier,
+        /// The maximum number of verifiers is reached.
+        TooManyVerifiers,
+        /// The wanted verifier is not found in the Verifiers Storage
+        VerifierNotFound,
+        /// The caller isn't a verifier.
+        NotAVerifier,
+        /// The verifier fee is higher than the maximum fee accepted by the candidate.
+        FeeTooHigh,
+        /// An attestation was already requested from this verifier.
+        AttestationAlreadyRequested,
+        /// No attestation was requested from this verifier.
+        AttestationNotRequested,
+        /// The verifier already attested this candidacy.
+        AlreadyAttested,
     }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// To be an artist, the caller have to candidate first.
         /// This will create the candidate profile with the given fields:
         ///
@@ -221,26 +1184,11 @@ pub mod pallet {
         ///
         /// NOTE: This can only be done once for an account.
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::submit_candidacy(T::NameMaxLength::get()))]
+        #[pallet::weight(T::WeightInfo::submit_candidacy(name.len() as u32))]
         pub fn submit_candidacy(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
             let caller = ensure_signed(origin)?;
 
-            // Check if the caller isn't neither a candidate nor an artist
-            ensure!(!Self::is_artist(&caller), Error::<T>::AlreadyAnArtist);
-            ensure!(!Self::is_candidate(&caller), Error::<T>::AlreadyACandidate);
-
-            let candidate = CandidateData {
-                name: name.try_into().map_err(|_| Error::<T>::NameTooLong)?,
-                created_at: <frame_system::Pallet<T>>::block_number(),
-            };
-
-            Self::reserve_deposit(&caller)?;
-
-            <Candidates<T>>::insert(caller.clone(), candidate);
-
-            Self::deposit_event(Event::<T>::CandidateAdded(caller));
-
-            Ok(())
+            Self::do_submit_candidacy(&caller, name, &caller)
         }
 
         /// Withdraw candidacy to become an artist and get deposit back.
@@ -249,17 +1197,22 @@ pub mod pallet {
         pub fn withdraw_candidacy(origin: OriginFor<T>) -> DispatchResult {
             let caller = Self::ensure_candidate(origin)?;
 
-            <Candidates<T>>::remove(&caller);
+            Self::remove_candidacy(&caller);
 
-            // returns deposit to the caller
-            Self::unreserve_deposit(&caller)?;
+            // returns deposit to the caller or its sponsor
+            let (depositor, deposit) = Self::unreserve_deposit(&caller)?;
 
-            Self::deposit_event(Event::<T>::CandidateWithdrew(caller));
+            Self::deposit_event(Event::<T, I>::CandidateWithdrew {
+                candidate: caller,
+                depositor,
+                deposit,
+            });
 
             Ok(())
         }
 
-        /// Approve a candidate and level up his account to be an artist.
+        /// Approve a candidate and level up his account to be an artist once
+        /// `T::ApprovalDelay` blocks passed without veto.
         ///
         /// May only be called from `T::AdminOrigin`.
         #[pallet::call_index(2)]
@@ -267,22 +1220,7 @@ pub mod pallet {
         pub fn approve_candidacy(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
             T::AdminOrigin::ensure_origin(origin)?;
 
-            ensure!(!Self::is_artist(&who), Error::<T>::AlreadyAnArtist);
-
-            let candidate =
-                <Candidates<T>>::try_get(&who).or_else(|_| Err(Error::<T>::CandidateNotFound))?;
-
-            let artist = ArtistData {
-                name: candidate.name,
-                created_at: <frame_system::Pallet<T>>::block_number(),
-            };
-
-            <Artists<T>>::insert(who.clone(), artist);
-
-            <Candidates<T>>::remove(&who);
-
-            Self::deposit_event(Event::<T>::CandidateApproved(who));
-            Ok(())
+            Self::schedule_approval(&who, ApprovalSource::Admin)
         }
 
         #[pallet::call_index(3)]
@@ -292,15 +1230,20 @@ pub mod pallet {
         )]
         pub fn call_as_artist(
             origin: OriginFor<T>,
-            call: Box<<T as Config>::Call>,
+            call: Box<<T as Config<I>>::Call>,
         ) -> DispatchResultWithPostInfo {
-            let caller = ensure_signed(origin)?;
-            ensure!(Self::is_artist(&caller), Error::<T>::NotAnArtist);
+            let caller = Self::ensure_artist(origin)?;
 
             let dispatch_hash = T::Hashing::hash_of(&call);
-            let result = call.dispatch(RawOrigin::Artist(caller).into());
+            // Only the actions taken in this pallet build up the reputation, any other call
+            // could be repeated at will
+            let is_artist_action = call.is_sub_type().is_some();
+            let result = call.dispatch(RawOrigin::Artist(caller.clone()).into());
+            if result.is_ok() && is_artist_action {
+                Self::update_reputation(&caller, |stats| stats.artist_calls.saturating_inc());
+            }
 
-            Self::deposit_event(Event::<T>::ArtistExecuted {
+            Self::deposit_event(Event::<T, I>::ArtistExecuted {
                 dispatch_hash,
                 result: result.map(|_| ()).map_err(|e| e.error),
             });
@@ -310,6 +1253,9 @@ pub mod pallet {
                 .into())
         }
 
+        /// Dispatch a call as a candidate, within the call budget of the era.
+        /// Up to `T::MaxCandidateCalls` calls weighting at most `T::MaxCandidateCallWeight`
+        /// could be dispatched per `T::CandidateEraLength` blocks.
         #[pallet::call_index(4)]
         #[pallet::weight(
             T::WeightInfo::call_as_candidate()
@@ -317,15 +1263,17 @@ pub mod pallet {
         )]
         pub fn call_as_candidate(
             origin: OriginFor<T>,
-            call: Box<<T as Config>::Call>,
+            call: Box<<T as Config<I>>::Call>,
         ) -> DispatchResultWithPostInfo {
             let caller = ensure_signed(origin)?;
-            ensure!(Self::is_candidate(&caller), Error::<T>::NotACandidate);
+            ensure!(Self::is_candidate(&caller), Error::<T, I>::NotACandidate);
+
+            Self::consume_call_budget(&caller, call.get_dispatch_info().weight)?;
 
             let dispatch_hash = T::Hashing::hash_of(&call);
             let result = call.dispatch(RawOrigin::Candidate(caller).into());
 
-            Self::deposit_event(Event::<T>::CandidateExecuted {
+            Self::deposit_event(Event::<T, I>::CandidateExecuted {
                 dispatch_hash,
                 result: result.map(|_| ()).map_err(|e| e.error),
             });
@@ -334,6 +1282,1072 @@ pub mod pallet {
                 .map(|w| T::WeightInfo::call_as_candidate().saturating_add(w))
                 .into())
         }
+
+        /// Add a verifier allowed to attest candidacies for the given `fee`.
+        ///
+        /// May only be called from `T::AdminOrigin`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::add_verifier())]
+        pub fn add_verifier(
+            origin: OriginFor<T>,
+            verifier: T::AccountId,
+            #[pallet::compact] fee: BalanceOf<T, I>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !<Verifiers<T, I>>::contains_key(&verifier),
+                Error::<T, I>::AlreadyAVerifier
+            );
+            ensure!(
+                <Verifiers<T, I>>::count() < T::MaxVerifiers::get(),
+                Error::<T, I>::TooManyVerifiers
+            );
+
+            <Verifiers<T, I>>::insert(&verifier, fee);
+
+            Self::deposit_event(Event::<T, I>::VerifierAdded { verifier, fee });
+            Ok(())
+        }
+
+        /// Remove a verifier. Its pending requests could still be cancelled by the candidates.
+        ///
+        /// May only be called from `T::AdminOrigin`.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::remove_verifier())]
+        pub fn remove_verifier(origin: OriginFor<T>, verifier: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            <Verifiers<T, I>>::take(&verifier).ok_or(Error::<T, I>::VerifierNotFound)?;
+
+            Self::deposit_event(Event::<T, I>::VerifierRemoved { verifier });
+            Ok(())
+        }
+
+        /// Set the fee charged by the calling verifier for an attestation.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_verifier_fee())]
+        pub fn set_verifier_fee(
+            origin: OriginFor<T>,
+            #[pallet::compact] fee: BalanceOf<T, I>,
+        ) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin)?;
+
+            <Verifiers<T, I>>::insert(&verifier, fee);
+
+            Self::deposit_event(Event::<T, I>::VerifierFeeSet { verifier, fee });
+            Ok(())
+        }
+
+        /// Ask a verifier to attest the candidacy of the caller.
+        /// The verifier fee is held from the caller until the verifier gives its verdict.
+        ///
+        /// `max_fee:` The maximum fee the caller accepts to pay the verifier.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::request_attestation())]
+        pub fn request_attestation(
+            origin: OriginFor<T>,
+            verifier: T::AccountId,
+            #[pallet::compact] max_fee: BalanceOf<T, I>,
+        ) -> DispatchResult {
+            let caller = Self::ensure_candidate(origin)?;
+
+            let fee = <Verifiers<T, I>>::get(&verifier).ok_or(Error::<T, I>::VerifierNotFound)?;
+            ensure!(fee <= max_fee, Error::<T, I>::FeeTooHigh);
+            ensure!(
+                !<AttestationRequests<T, I>>::contains_key(&caller, &verifier),
+                Error::<T, I>::AttestationAlreadyRequested
+            );
+            ensure!(
+                !<Attestations<T, I>>::contains_key(&caller, &verifier),
+                Error::<T, I>::AlreadyAttested
+            );
+
+            T::Currency::reserve(&caller, fee).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+
+            <AttestationRequests<T, I>>::insert(&caller, &verifier, fee);
+
+            Self::deposit_event(Event::<T, I>::AttestationRequested {
+                candidate: caller,
+                verifier,
+                fee,
+            });
+            Ok(())
+        }
+
+        /// Cancel a pending attestation request of the caller and release the held fee.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::cancel_attestation_request())]
+        pub fn cancel_attestation_request(
+            origin: OriginFor<T>,
+            verifier: T::AccountId,
+        ) -> DispatchResult {
+            let caller = Self::ensure_candidate(origin)?;
+
+            let fee = <AttestationRequests<T, I>>::take(&caller, &verifier)
+                .ok_or(Error::<T, I>::AttestationNotRequested)?;
+            T::Currency::unreserve(&caller, fee);
+
+            Self::deposit_event(Event::<T, I>::AttestationRequestCancelled {
+                candidate: caller,
+                verifier,
+            });
+            Ok(())
+        }
+
+        /// Give a verdict on a candidacy that requested the caller attestation.
+        /// The held fee is paid to the caller whatever the verdict.
+        ///
+        /// The approval of the candidate is scheduled once `T::AttestationQuorum`
+        /// distinct verifiers approved it.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::attest_candidacy(T::MaxVerifiers::get()))]
+        pub fn attest_candidacy(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            verdict: Verdict,
+        ) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin)?;
+
+            let fee = <AttestationRequests<T, I>>::take(&candidate, &verifier)
+                .ok_or(Error::<T, I>::AttestationNotRequested)?;
+            T::Currency::repatriate_reserved(&candidate, &verifier, fee, BalanceStatus::Free)?;
+
+            <Attestations<T, I>>::insert(&candidate, &verifier, verdict);
+
+            Self::deposit_event(Event::<T, I>::CandidacyAttested {
+                candidate: candidate.clone(),
+                verifier,
+                verdict,
+            });
+
+            if verdict == Verdict::Approved
+                && !Self::is_approval_pending(&candidate)
+                && Self::approvals_of(&candidate) >= T::AttestationQuorum::get()
+            {
+                Self::try_schedule_approval(&candidate, ApprovalSource::Attestations);
+            }
+
+            Ok(())
+        }
+
+        /// Upgrade or downgrade the verification level of an artist.
+        /// The difference between the two level deposits is held from or released to the artist.
+        ///
+        /// May only be called from `T::AdminOrigin` or by a verifier.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_verification_level())]
+        pub fn set_verification_level(
+            origin: OriginFor<T>,
+            artist: T::AccountId,
+            level: VerificationLevel,
+        ) -> DispatchResult {
+            let verifier = Self::ensure_admin_or_verifier(origin)?;
+
+            Self::do_set_verification_level(&artist, level, verifier)
+        }
+
+        /// Attach an evidence to the candidacy of the caller, for the verifiers and
+        /// the admin to review it. `T::EvidenceDeposit` is held for each evidence and
+        /// released once the candidacy is approved, rejected or withdrawn.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::add_candidacy_evidence())]
+        pub fn add_candidacy_evidence(
+            origin: OriginFor<T>,
+            evidence: EvidenceOf<T, I>,
+        ) -> DispatchResult {
+            let caller = Self::ensure_candidate(origin)?;
+
+            let deposit = T::EvidenceDeposit::get();
+            <CandidacyEvidence<T, I>>::try_mutate(&caller, |items| -> DispatchResult {
+                items
+                    .try_push((evidence.clone(), deposit))
+                    .map_err(|_| Error::<T, I>::TooManyEvidenceItems)?;
+                T::Currency::reserve(&caller, deposit)
+                    .map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T, I>::CandidacyEvidenceAdded {
+                candidate: caller,
+                evidence,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Reject a candidacy and give the deposits back to the candidate.
+        ///
+        /// May only be called from `T::AdminOrigin`.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::reject_candidacy())]
+        pub fn reject_candidacy(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(Self::is_candidate(&who), Error::<T, I>::CandidateNotFound);
+
+            Self::remove_candidacy(&who);
+            let (depositor, deposit) = Self::unreserve_deposit(&who)?;
+
+            Self::deposit_event(Event::<T, I>::CandidateRejected {
+                candidate: who,
+                depositor,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Challenge an artist suspected to be an impersonation.
+        /// `T::ChallengeBond` is held from the caller until the dispute is closed.
+        ///
+        /// `evidence_hash:` The content hash of the evidence backing the challenge.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::challenge_artist())]
+        pub fn challenge_artist(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            evidence_hash: T::Hash,
+        ) -> DispatchResult {
+            let challenger = ensure_signed(origin)?;
+
+            ensure!(
+                <Artists<T, I>>::contains_key(&who),
+                Error::<T, I>::ArtistNotFound
+            );
+            ensure!(
+                !<Disputes<T, I>>::contains_key(&who),
+                Error::<T, I>::DisputeAlreadyOpened
+            );
+
+            let bond = T::ChallengeBond::get();
+            T::Currency::reserve(&challenger, bond).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+
+            let dispute = Dispute {
+                challenger: challenger.clone(),
+                evidence_hash,
+                bond,
+                opened_at: <frame_system::Pallet<T>>::block_number(),
+            };
+            <Disputes<T, I>>::insert(&who, dispute);
+
+            Self::deposit_event(Event::<T, I>::ArtistChallenged {
+                artist: who,
+                challenger,
+                evidence_hash,
+                bond,
+            });
+            Ok(())
+        }
+
+        /// Resolve the challenge opened against an artist.
+        ///
+        /// `uphold:` If `true` the artist is revoked, the challenger bond is released and
+        /// `T::ChallengerReward` of the artist deposit is awarded to the challenger, the rest
+        /// being slashed. Otherwise the challenger bond is slashed.
+        ///
+        /// May only be called from `T::AdminOrigin`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(
+            T::WeightInfo::resolve_dispute()
+                .saturating_add(T::WeightInfo::reap_followers(T::MaxFollowersReaped::get()))
+                .saturating_add(
+                    T::WeightInfo::dissolve_collaboration()
+                        .saturating_mul(T::MaxCollaborations::get().into())
+                )
+                .saturating_add(
+                    T::WeightInfo::cancel_collaboration()
+                        .saturating_mul(T::MaxPendingCollaborations::get().into())
+                )
+                .saturating_add(
+                    T::WeightInfo::retire_split().saturating_mul(T::MaxSplitsPerArtist::get().into())
+                )
+        )]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            uphold: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let dispute = <Disputes<T, I>>::take(&who).ok_or(Error::<T, I>::DisputeNotFound)?;
+
+            if uphold {
+                let artist = Self::do_revoke_artist(&who)?;
+                T::Currency::unreserve(&dispute.challenger, dispute.bond);
+
+                // A sponsor backing an impersonation loses the creation deposit it holds
+                let reward = match <Sponsors<T, I>>::take(&who) {
+                    Some(sponsor) => {
+                        let creation_deposit = T::CreationDepositAmount::get();
+                        Self::confiscate_deposit(
+                            &who,
+                            artist.deposit.saturating_sub(creation_deposit),
+                            &dispute.challenger,
+                        )?
+                        .saturating_add(Self::confiscate_deposit(
+                            &sponsor,
+                            creation_deposit,
+                            &dispute.challenger,
+                        )?)
+                    }
+                    // Nothing is held from a deposit exempt artist
+                    None if artist.deposit_exempt => Zero::zero(),
+                    None => Self::confiscate_deposit(&who, artist.deposit, &dispute.challenger)?,
+                };
+
+                Self::deposit_event(Event::<T, I>::DisputeUpheld {
+                    artist: who,
+                    challenger: dispute.challenger,
+                    reward,
+                });
+            } else {
+                let (imbalance, _) = T::Currency::slash_reserved(&dispute.challenger, dispute.bond);
+                T::Slashed::on_unbalanced(imbalance);
+                Self::update_reputation(&who, |stats| stats.dismissed_disputes.saturating_inc());
+
+                Self::deposit_event(Event::<T, I>::DisputeDismissed {
+                    artist: who,
+                    challenger: dispute.challenger,
+                    slashed: dispute.bond,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Close a challenge left unresolved for `T::DisputeTimeout` blocks and
+        /// release the challenger bond.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::close_expired_dispute())]
+        pub fn close_expired_dispute(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let dispute = <Disputes<T, I>>::get(&who).ok_or(Error::<T, I>::DisputeNotFound)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number()
+                    >= dispute.opened_at.saturating_add(T::DisputeTimeout::get()),
+                Error::<T, I>::DisputeNotExpired
+            );
+
+            <Disputes<T, I>>::remove(&who);
+            T::Currency::unreserve(&dispute.challenger, dispute.bond);
+
+            Self::deposit_event(Event::<T, I>::DisputeExpired {
+                artist: who,
+                challenger: dispute.challenger,
+            });
+            Ok(())
+        }
+
+        /// Vouch for a candidate as an artist. Each artist could endorse a candidate once.
+        ///
+        /// The approval of the candidate is scheduled once it reaches
+        /// `T::EndorsementThreshold` endorsements.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::endorse_candidate(T::MaxEndorsements::get()))]
+        pub fn endorse_candidate(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            let caller = Self::ensure_artist(origin)?;
+
+            ensure!(Self::is_candidate(&who), Error::<T, I>::CandidateNotFound);
+
+            let endorsements = <Endorsements<T, I>>::try_mutate(
+                &who,
+                |endorsements| -> Result<u32, DispatchError> {
+                    ensure!(
+                        !endorsements.contains(&caller),
+                        Error::<T, I>::AlreadyEndorsed
+                    );
+                    endorsements
+                        .try_push(caller.clone())
+                        .map_err(|_| Error::<T, I>::TooManyEndorsements)?;
+                    Ok(endorsements.len() as u32)
+                },
+            )?;
+
+            Self::deposit_event(Event::<T, I>::CandidateEndorsed {
+                candidate: who.clone(),
+                artist: caller,
+                endorsements,
+            });
+
+            if !Self::is_approval_pending(&who)
+                && T::EndorsementThreshold::get()
+                    .map_or(false, |threshold| endorsements >= threshold)
+            {
+                Self::try_schedule_approval(&who, ApprovalSource::Endorsements);
+            }
+
+            Ok(())
+        }
+
+        /// Suspend an artist until the given block, treating it as inactive meanwhile
+        /// without losing its record. Suspending an already suspended artist replaces
+        /// the current suspension.
+        ///
+        /// May only be called from `T::AdminOrigin`.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::suspend_artist())]
+        pub fn suspend_artist(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            until: BlockNumberFor<T>,
+            reason: SuspensionReason,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                until > <frame_system::Pallet<T>>::block_number(),
+                Error::<T, I>::InvalidSuspensionEnd
+            );
+
+            let was_active =
+                <Artists<T, I>>::try_mutate(&who, |maybe_record| -> Result<bool, DispatchError> {
+                    let record = maybe_record.as_mut().ok_or(Error::<T, I>::ArtistNotFound)?;
+                    if let ArtistStatus::Suspended { until, .. } = record.status {
+                        Self::remove_suspension_expiry(&who, until);
+                    }
+                    let was_active = !record.is_suspended();
+                    record.status = ArtistStatus::Suspended { until, reason };
+                    Ok(was_active)
+                })?;
+
+            <SuspensionExpiries<T, I>>::try_append(until, &who)
+                .map_err(|_| Error::<T, I>::TooManySuspensions)?;
+
+            if was_active {
+                Self::notify_artists_changed(&[], &[who.clone()]);
+            }
+
+            Self::deposit_event(Event::<T, I>::ArtistSuspended {
+                artist: who,
+                until,
+                reason,
+            });
+            Ok(())
+        }
+
+        /// Lift the suspension of an artist before its end.
+        ///
+        /// May only be called from `T::AdminOrigin`.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::unsuspend_artist())]
+        pub fn unsuspend_artist(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let record = <Artists<T, I>>::get(&who).ok_or(Error::<T, I>::ArtistNotFound)?;
+            let ArtistStatus::Suspended { until, .. } = record.status else {
+                return Err(Error::<T, I>::ArtistNotSuspended.into());
+            };
+
+            Self::remove_suspension_expiry(&who, until);
+            Self::do_lift_suspension(&who, true);
+            Self::notify_artists_changed(&[who], &[]);
+
+            Ok(())
+        }
+
+        /// Cancel the scheduled approval of a candidate before its enactment.
+        /// The account stays a candidate and could be approved again.
+        ///
+        /// May only be called from `T::AdminOrigin` or `T::VetoOrigin`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::veto_approval())]
+        pub fn veto_approval(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            if let Err(origin) = T::VetoOrigin::try_origin(origin) {
+                T::AdminOrigin::ensure_origin(origin)?;
+            }
+
+            ensure!(
+                Self::cancel_scheduled_approval(&who),
+                Error::<T, I>::ApprovalNotScheduled
+            );
+
+            Self::deposit_event(Event::<T, I>::ApprovalVetoed { candidate: who });
+            Ok(())
+        }
+
+        /// Submit a candidacy on behalf of `candidate`, holding the creation deposit
+        /// from the caller. The deposit goes back to the caller once the candidacy is
+        /// withdrawn or rejected, or once the candidate takes it over.
+        ///
+        /// `name:` The name of the artist.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::sponsor_candidacy(name.len() as u32))]
+        pub fn sponsor_candidacy(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+
+            Self::do_submit_candidacy(&candidate, name, &sponsor)?;
+            if sponsor != candidate {
+                <Sponsors<T, I>>::insert(&candidate, &sponsor);
+
+                Self::deposit_event(Event::<T, I>::CandidateSponsored {
+                    candidate,
+                    sponsor,
+                    deposit: T::CreationDepositAmount::get(),
+                });
+            }
+            Ok(())
+        }
+
+        /// Hold the creation deposit from the caller, a sponsored candidate or artist,
+        /// and release it to the sponsor.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::take_over_deposit())]
+        pub fn take_over_deposit(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let sponsor = <Sponsors<T, I>>::get(&who).ok_or(Error::<T, I>::NotSponsored)?;
+
+            Self::reserve_deposit(&who)?;
+            <Sponsors<T, I>>::remove(&who);
+            // The deposit held for a candidacy is recorded, until the approval
+            let deposit = if Self::is_candidate(&who) {
+                <CandidacyDeposits<T, I>>::mutate(&who, |held| {
+                    held.replace(T::CreationDepositAmount::get())
+                })
+            } else {
+                None
+            }
+            .unwrap_or_else(T::CreationDepositAmount::get);
+            T::Currency::unreserve(&sponsor, deposit);
+
+            Self::deposit_event(Event::<T, I>::SponsorshipEnded {
+                who,
+                sponsor,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Register an artist without candidacy, e.g. an artist onboarded through a
+        /// partnership. A candidate is registered in place of its candidacy, whose
+        /// deposits are released.
+        ///
+        /// `profile:` The name and metadata of the artist.
+        /// `deposit_exempt:` If `true` no deposit is held from the artist, whatever its
+        /// verification level.
+        ///
+        /// May only be called from `T::AdminOrigin`.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::force_register_artist())]
+        pub fn force_register_artist(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            profile: ProfileOf<T, I>,
+            deposit_exempt: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Self::do_force_register_artist(&who, profile, deposit_exempt)
+        }
+
+        /// Propose a collaboration on a work to another artist, holding
+        /// `T::CollaborationDeposit` until the collaboration is confirmed or cancelled.
+        ///
+        /// `work_hash:` The content hash of the work.
+        /// `metadata:` Free-form metadata about the collaboration.
+        ///
+        /// May also be dispatched through `call_as_artist`.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::propose_collaboration())]
+        pub fn propose_collaboration(
+            origin: OriginFor<T>,
+            counterparty: T::AccountId,
+            work_hash: T::Hash,
+            metadata: BoundedVec<u8, T::MetadataMaxLength>,
+        ) -> DispatchResult {
+            let proposer = Self::ensure_artist_origin(origin)?;
+
+            ensure!(proposer != counterparty, Error::<T, I>::SelfCollaboration);
+            ensure!(
+                Self::is_artist(&counterparty),
+                Error::<T, I>::ArtistNotFound
+            );
+
+            let id = <NextCollaborationId<T, I>>::get();
+            for artist in [&proposer, &counterparty] {
+                <PendingCollaborations<T, I>>::try_append(artist, id)
+                    .map_err(|_| Error::<T, I>::TooManyPendingCollaborations)?;
+            }
+
+            let deposit = T::CollaborationDeposit::get();
+            T::Currency::reserve(&proposer, deposit).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+            <NextCollaborationId<T, I>>::put(id.saturating_add(1));
+            let collaboration = Collaboration {
+                proposer: proposer.clone(),
+                counterparty: counterparty.clone(),
+                work_hash,
+                metadata,
+                deposit,
+                status: CollaborationStatus::Proposed {
+                    at: <frame_system::Pallet<T>>::block_number(),
+                },
+            };
+            <Collaborations<T, I>>::insert(id, collaboration);
+
+            Self::deposit_event(Event::<T, I>::CollaborationProposed {
+                id,
+                proposer,
+                counterparty,
+                work_hash,
+            });
+            Ok(())
+        }
+
+        /// Confirm a collaboration proposed to the caller, releasing the deposit of
+        /// the proposer. The collaboration is then listed for both artists.
+        ///
+        /// May also be dispatched through `call_as_artist`.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::accept_collaboration())]
+        pub fn accept_collaboration(origin: OriginFor<T>, id: CollaborationId) -> DispatchResult {
+            let caller = Self::ensure_artist_origin(origin)?;
+
+            <Collaborations<T, I>>::try_mutate(id, |maybe_collaboration| -> DispatchResult {
+                let collaboration = maybe_collaboration
+                    .as_mut()
+                    .ok_or(Error::<T, I>::CollaborationNotFound)?;
+                ensure!(
+                    collaboration.counterparty == caller,
+                    Error::<T, I>::NotACollaborator
+                );
+                ensure!(
+                    matches!(collaboration.status, CollaborationStatus::Proposed { .. }),
+                    Error::<T, I>::CollaborationAlreadyConfirmed
+                );
+                ensure!(
+                    Self::is_artist(&collaboration.proposer),
+                    Error::<T, I>::ArtistNotFound
+                );
+
+                for artist in [&collaboration.proposer, &collaboration.counterparty] {
+                    <ArtistCollaborations<T, I>>::try_append(artist, id)
+                        .map_err(|_| Error::<T, I>::TooManyCollaborations)?;
+                }
+                Self::remove_pending_collaboration(id, collaboration);
+                T::Currency::unreserve(&collaboration.proposer, collaboration.deposit);
+                collaboration.deposit = Zero::zero();
+                collaboration.status = CollaborationStatus::Confirmed {
+                    at: <frame_system::Pallet<T>>::block_number(),
+                };
+
+                Self::deposit_event(Event::<T, I>::CollaborationConfirmed {
+                    id,
+                    proposer: collaboration.proposer.clone(),
+                    counterparty: caller,
+                });
+                Ok(())
+            })
+        }
+
+        /// Cancel a collaboration which isn't confirmed yet, releasing the deposit of
+        /// the proposer.
+        ///
+        /// May be called by either the proposer or the counterparty, also through
+        /// `call_as_artist`.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::cancel_collaboration())]
+        pub fn cancel_collaboration(origin: OriginFor<T>, id: CollaborationId) -> DispatchResult {
+            let caller = Self::ensure_signed_or_artist(origin)?;
+
+            let collaboration =
+                <Collaborations<T, I>>::get(id).ok_or(Error::<T, I>::CollaborationNotFound)?;
+            ensure!(
+                caller == collaboration.proposer || caller == collaboration.counterparty,
+                Error::<T, I>::NotACollaborator
+            );
+            ensure!(
+                matches!(collaboration.status, CollaborationStatus::Proposed { .. }),
+                Error::<T, I>::CollaborationAlreadyConfirmed
+            );
+
+            Self::do_cancel_collaboration(id, &collaboration, &caller);
+            Ok(())
+        }
+
+        /// Dissolve a confirmed collaboration, which is no longer listed for both artists.
+        ///
+        /// May be called by either artist of the collaboration, also through
+        /// `call_as_artist`.
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::dissolve_collaboration())]
+        pub fn dissolve_collaboration(origin: OriginFor<T>, id: CollaborationId) -> DispatchResult {
+            let caller = Self::ensure_signed_or_artist(origin)?;
+
+            let collaboration =
+                <Collaborations<T, I>>::get(id).ok_or(Error::<T, I>::CollaborationNotFound)?;
+            ensure!(
+                caller == collaboration.proposer || caller == collaboration.counterparty,
+                Error::<T, I>::NotACollaborator
+            );
+            ensure!(
+                matches!(collaboration.status, CollaborationStatus::Confirmed { .. }),
+                Error::<T, I>::CollaborationNotConfirmed
+            );
+
+            Self::do_dissolve_collaboration(id, &collaboration, &caller);
+            Ok(())
+        }
+
+        /// Register a work in the catalog of the caller, holding `T::WorkDeposit`
+        /// until the work is retired.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::register_work())]
+        pub fn register_work(origin: OriginFor<T>, work: WorkOf<T, I>) -> DispatchResult {
+            let caller = Self::ensure_artist(origin)?;
+
+            ensure!(work.has_valid_codes(), Error::<T, I>::InvalidWorkCode);
+            ensure!(
+                !<WorksByIsrc<T, I>>::contains_key(work.isrc),
+                Error::<T, I>::WorkAlreadyRegistered
+            );
+
+            let deposit = T::WorkDeposit::get();
+            <Catalog<T, I>>::try_mutate(&caller, |works| -> DispatchResult {
+                works
+                    .try_push((work.clone(), deposit))
+                    .map_err(|_| Error::<T, I>::TooManyWorks)?;
+                T::Currency::reserve(&caller, deposit)
+                    .map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+                Ok(())
+            })?;
+            <WorksByIsrc<T, I>>::insert(work.isrc, &caller);
+
+            Self::deposit_event(Event::<T, I>::WorkRegistered {
+                artist: caller,
+                isrc: work.isrc,
+                content_hash: work.content_hash,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Replace the details of a work of the caller catalog, the work being
+        /// identified by its ISRC.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::update_work())]
+        pub fn update_work(origin: OriginFor<T>, work: WorkOf<T, I>) -> DispatchResult {
+            let caller = Self::ensure_artist(origin)?;
+
+            ensure!(work.has_valid_codes(), Error::<T, I>::InvalidWorkCode);
+
+            let isrc = work.isrc;
+            <Catalog<T, I>>::try_mutate(&caller, |works| -> DispatchResult {
+                let (entry, _) = works
+                    .iter_mut()
+                    .find(|(entry, _)| entry.isrc == isrc)
+                    .ok_or(Error::<T, I>::WorkNotFound)?;
+                *entry = work;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T, I>::WorkUpdated {
+                artist: caller,
+                isrc,
+            });
+            Ok(())
+        }
+
+        /// Remove a work from the catalog of the caller and release its deposit.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::retire_work())]
+        pub fn retire_work(origin: OriginFor<T>, isrc: Isrc) -> DispatchResult {
+            let caller = Self::ensure_artist(origin)?;
+
+            let deposit = <Catalog<T, I>>::try_mutate(
+                &caller,
+                |works| -> Result<BalanceOf<T, I>, DispatchError> {
+                    let index = works
+                        .iter()
+                        .position(|(work, _)| work.isrc == isrc)
+                        .ok_or(Error::<T, I>::WorkNotFound)?;
+                    Ok(works.remove(index).1)
+                },
+            )?;
+            <WorksByIsrc<T, I>>::remove(isrc);
+            T::Currency::unreserve(&caller, deposit);
+
+            Self::deposit_event(Event::<T, I>::WorkRetired {
+                artist: caller,
+                isrc,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Define a royalty split owned by the caller, holding `T::SplitDeposit` for
+        /// its first version.
+        ///
+        /// `shares:` The payees with their shares, summing to 100%.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::create_split(shares.len() as u32))]
+        pub fn create_split(origin: OriginFor<T>, shares: SharesOf<T, I>) -> DispatchResult {
+            let caller = Self::ensure_artist_origin(origin)?;
+
+            Self::ensure_valid_shares(&shares)?;
+            let deposit = T::SplitDeposit::get();
+            T::Currency::reserve(&caller, deposit).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+
+            let id = <NextSplitId<T, I>>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            <ArtistSplits<T, I>>::try_append(&caller, id)
+                .map_err(|_| Error::<T, I>::TooManySplits)?;
+            let split = RoyaltySplit {
+                owner: caller.clone(),
+                version: 0,
+                deposit,
+                locked: false,
+            };
+            <RoyaltySplits<T, I>>::insert(id, split);
+            <SplitVersions<T, I>>::insert(id, 0, shares);
+
+            Self::deposit_event(Event::<T, I>::SplitCreated {
+                id,
+                owner: caller,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Replace the shares of a royalty split of the caller with a new version,
+        /// holding `T::SplitDeposit` for it. The previous versions stay resolvable, their
+        /// deposits are held until the split is retired.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::update_split(shares.len() as u32))]
+        pub fn update_split(
+            origin: OriginFor<T>,
+            id: SplitId,
+            shares: SharesOf<T, I>,
+        ) -> DispatchResult {
+            let caller = Self::ensure_artist_origin(origin)?;
+
+            Self::ensure_valid_shares(&shares)?;
+            let (version, deposit) = <RoyaltySplits<T, I>>::try_mutate(
+                id,
+                |maybe_split| -> Result<(SplitVersion, BalanceOf<T, I>), DispatchError> {
+                    let split = maybe_split.as_mut().ok_or(Error::<T, I>::SplitNotFound)?;
+                    ensure!(split.owner == caller, Error::<T, I>::NotSplitOwner);
+                    ensure!(!split.locked, Error::<T, I>::SplitLocked);
+                    ensure!(
+                        split.version.saturating_add(1) < T::MaxSplitVersions::get(),
+                        Error::<T, I>::TooManySplitVersions
+                    );
+
+                    let deposit = T::SplitDeposit::get();
+                    T::Currency::reserve(&caller, deposit)
+                        .map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+                    split.version = split.version.saturating_add(1);
+                    split.deposit = split.deposit.saturating_add(deposit);
+                    Ok((split.version, deposit))
+                },
+            )?;
+            <SplitVersions<T, I>>::insert(id, version, shares);
+
+            Self::deposit_event(Event::<T, I>::SplitUpdated {
+                id,
+                version,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Lock a royalty split of the caller, making its active version final.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::lock_split())]
+        pub fn lock_split(origin: OriginFor<T>, id: SplitId) -> DispatchResult {
+            let caller = Self::ensure_artist_origin(origin)?;
+
+            let version = <RoyaltySplits<T, I>>::try_mutate(
+                id,
+                |maybe_split| -> Result<SplitVersion, DispatchError> {
+                    let split = maybe_split.as_mut().ok_or(Error::<T, I>::SplitNotFound)?;
+                    ensure!(split.owner == caller, Error::<T, I>::NotSplitOwner);
+                    ensure!(!split.locked, Error::<T, I>::SplitLocked);
+
+                    split.locked = true;
+                    Ok(split.version)
+                },
+            )?;
+
+            Self::deposit_event(Event::<T, I>::SplitLocked { id, version });
+            Ok(())
+        }
+
+        /// Retire a royalty split of the caller, locked or not, removing all its versions
+        /// and releasing their deposits. The split no longer counts toward
+        /// `T::MaxSplitsPerArtist`.
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::retire_split())]
+        pub fn retire_split(origin: OriginFor<T>, id: SplitId) -> DispatchResult {
+            let caller = Self::ensure_artist_origin(origin)?;
+
+            let split = <RoyaltySplits<T, I>>::get(id).ok_or(Error::<T, I>::SplitNotFound)?;
+            ensure!(split.owner == caller, Error::<T, I>::NotSplitOwner);
+
+            <ArtistSplits<T, I>>::mutate_exists(&caller, |maybe_ids| {
+                if let Some(ids) = maybe_ids {
+                    ids.retain(|other| *other != id);
+                    if ids.is_empty() {
+                        *maybe_ids = None;
+                    }
+                }
+            });
+            Self::do_remove_split(id, &split);
+            Ok(())
+        }
+
+        /// Issue the fan token of the caller, owned by the caller and named after it,
+        /// minting the whole supply to the caller and holding `T::FanTokenDeposit`.
+        /// An artist issues a single token.
+        ///
+        /// `symbol:` The ticker of the token.
+        /// `decimals:` The number of decimals of the token.
+        /// `supply:` The amount of tokens minted to the caller.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::create_artist_token())]
+        pub fn create_artist_token(
+            origin: OriginFor<T>,
+            symbol: BoundedVec<u8, T::NameMaxLength>,
+            decimals: u8,
+            supply: AssetBalanceOf<T, I>,
+        ) -> DispatchResult {
+            let caller = Self::ensure_artist(origin)?;
+
+            Self::do_create_artist_token(&caller, symbol.into_inner(), decimals, supply)
+        }
+
+        /// Create the verified NFT collection of the caller, for an artist whose
+        /// collection wasn't created at its approval.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::create_artist_collection())]
+        pub fn create_artist_collection(origin: OriginFor<T>) -> DispatchResult {
+            let caller = Self::ensure_artist(origin)?;
+
+            let record = <Artists<T, I>>::get(&caller).ok_or(Error::<T, I>::ArtistNotFound)?;
+            ensure!(
+                record.collection.is_none(),
+                Error::<T, I>::CollectionAlreadyCreated
+            );
+
+            let collection = Self::do_create_collection(&caller)?;
+            <Artists<T, I>>::mutate(&caller, |maybe_record| {
+                if let Some(record) = maybe_record {
+                    record.collection = Some(collection);
+                }
+            });
+            Ok(())
+        }
+
+        /// Set the identity requirements of the candidacies, enforced at their submission
+        /// and their approval.
+        ///
+        /// May only be called from `T::AdminOrigin`.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::set_identity_policy())]
+        pub fn set_identity_policy(origin: OriginFor<T>, policy: IdentityPolicy) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            <CandidateIdentityPolicy<T, I>>::put(&policy);
+
+            Self::deposit_event(Event::<T, I>::IdentityPolicySet { policy });
+            Ok(())
+        }
+
+        /// Follow an artist, holding `T::FollowDeposit` until unfollowing it.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::follow_artist())]
+        pub fn follow_artist(origin: OriginFor<T>, artist_id: ArtistId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+
+            let artist =
+                <ArtistAccounts<T, I>>::get(artist_id).ok_or(Error::<T, I>::ArtistNotFound)?;
+            ensure!(
+                !<Followers<T, I>>::contains_key(artist_id, &caller),
+                Error::<T, I>::AlreadyFollowing
+            );
+
+            let deposit = T::FollowDeposit::get();
+            T::Currency::reserve(&caller, deposit).map_err(|_| Error::<T, I>::NotEnoughFunds)?;
+            <Followers<T, I>>::insert(artist_id, &caller, deposit);
+            <Artists<T, I>>::mutate(&artist, |maybe_record| {
+                if let Some(record) = maybe_record {
+                    record.followers.saturating_inc();
+                }
+            });
+
+            Self::deposit_event(Event::<T, I>::ArtistFollowed {
+                artist_id,
+                follower: caller,
+            });
+            Ok(())
+        }
+
+        /// Stop following an artist and get the follow deposit back, also once the
+        /// artist left.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::unfollow_artist())]
+        pub fn unfollow_artist(origin: OriginFor<T>, artist_id: ArtistId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+
+            let deposit =
+                <Followers<T, I>>::take(artist_id, &caller).ok_or(Error::<T, I>::NotFollowing)?;
+            T::Currency::unreserve(&caller, deposit);
+            if let Some(artist) = <ArtistAccounts<T, I>>::get(artist_id) {
+                <Artists<T, I>>::mutate(&artist, |maybe_record| {
+                    if let Some(record) = maybe_record {
+                        record.followers.saturating_dec();
+                    }
+                });
+            }
+
+            Self::deposit_event(Event::<T, I>::ArtistUnfollowed {
+                artist_id,
+                follower: caller,
+            });
+            Ok(())
+        }
+
+        /// Release up to `T::MaxFollowersReaped` follows of an artist who left, returning
+        /// their deposits to the followers.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::reap_followers(T::MaxFollowersReaped::get()))]
+        pub fn reap_followers(
+            origin: OriginFor<T>,
+            artist_id: ArtistId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            ensure!(
+                !<ArtistAccounts<T, I>>::contains_key(artist_id),
+                Error::<T, I>::ArtistStillRegistered
+            );
+
+            let count = Self::do_reap_followers(artist_id, T::MaxFollowersReaped::get());
+            ensure!(count > 0, Error::<T, I>::NoFollowers);
+
+            Ok(Some(T::WeightInfo::reap_followers(count)).into())
+        }
+
+        /// Score the reputation of an artist again, for its age to be accounted.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::refresh_reputation())]
+        pub fn refresh_reputation(origin: OriginFor<T>, artist: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            ensure!(
+                Self::is_registered_artist(&artist),
+                Error::<T, I>::ArtistNotFound
+            );
+            Self::update_reputation(&artist, |_| ());
+            Ok(())
+        }
     }
 }
 
@@ -347,31 +2361,42 @@ fn get_result_weight(result: DispatchResultWithPostInfo) -> Option<Weight> {
     }
 }
 
-pub struct EnsureArtist<AccountId>(PhantomData<AccountId>);
-impl<O: Into<Result<RawOrigin<AccountId>, O>> + From<RawOrigin<AccountId>>, AccountId: Decode>
-    EnsureOrigin<O> for EnsureArtist<AccountId>
+/// Ensure the origin was condoned by an artist which is still active, i.e. neither
+/// suspended nor revoked since the origin was created.
+pub struct EnsureArtist<T, I: 'static = ()>(PhantomData<(T, I)>);
+impl<
+        O: Into<Result<RawOrigin<T::AccountId, I>, O>> + From<RawOrigin<T::AccountId, I>>,
+        T: Config<I>,
+        I: 'static,
+    > EnsureOrigin<O> for EnsureArtist<T, I>
 {
-    type Success = AccountId;
+    type Success = T::AccountId;
 
     fn try_origin(o: O) -> Result<Self::Success, O> {
         o.into().and_then(|o| match o {
-            RawOrigin::Artist(id) => Ok(id),
-            _ => Err(O::from(o)),
+            RawOrigin::Artist(id) if Pallet::<T, I>::is_artist(&id) => Ok(id),
+            o => Err(O::from(o)),
         })
     }
 
     #[cfg(feature = "runtime-benchmarks")]
     fn try_successful_origin() -> Result<O, ()> {
         let zero_account_id =
-            AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
+            T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
                 .expect("infinite length input; no invalid inputs for type; qed");
+        if !Pallet::<T, I>::is_artist(&zero_account_id) {
+            <ArtistMembers<T, I> as frame_support::traits::SortedMembers<_>>::add(&zero_account_id);
+        }
         Ok(O::from(RawOrigin::Artist(zero_account_id)))
     }
 }
 
-pub struct EnsureCandidate<AccountId>(PhantomData<AccountId>);
-impl<O: Into<Result<RawOrigin<AccountId>, O>> + From<RawOrigin<AccountId>>, AccountId: Decode>
-    EnsureOrigin<O> for EnsureCandidate<AccountId>
+pub struct EnsureCandidate<AccountId, I: 'static = ()>(PhantomData<(AccountId, I)>);
+impl<
+        O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>,
+        AccountId: Decode,
+        I,
+    > EnsureOrigin<O> for EnsureCandidate<AccountId, I>
 {
     type Success = AccountId;
 
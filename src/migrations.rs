@@ -0,0 +1,125 @@
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// The storage layout before the artist records were versioned.
+pub mod v0 {
+    use super::*;
+
+    /// The artist data stored before `ArtistRecord`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct OldArtist<Name, BlockNumber> {
+        pub name: Name,
+        pub created_at: BlockNumber,
+    }
+
+    pub type OldArtistOf<T, I> =
+        OldArtist<BoundedVec<u8, <T as Config<I>>::NameMaxLength>, BlockNumberFor<T>>;
+
+    #[frame_support::storage_alias]
+    pub type Artists<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        OldArtistOf<T, I>,
+    >;
+}
+
+/// Translate the artists stored as name and creation block to an `ArtistRecord`.
+///
+/// Each artist gets an identifier and the default verification level. Its deposit is the
+/// `CreationDepositAmount` held by the previous version, the level deposit being left
+/// unheld. The artist count and the sorted artist and candidate sets are initialised
+/// along.
+pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+    fn on_runtime_upgrade() -> Weight {
+        let on_chain = Pallet::<T, I>::on_chain_storage_version();
+        if on_chain >= 1 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        let old_artists: Vec<_> = v0::Artists::<T, I>::drain().collect();
+        let migrated = old_artists.len() as u64;
+
+        let mut accounts = Vec::with_capacity(old_artists.len());
+        for (who, old) in old_artists {
+            let record = ArtistRecord {
+                id: Pallet::<T, I>::assign_artist_id(&who),
+                name: old.name,
+                metadata: Default::default(),
+                created_at: old.created_at,
+                level: VerificationLevel::default(),
+                deposit: T::CreationDepositAmount::get(),
+                deposit_exempt: false,
+                fan_token: None,
+                collection: None,
+                followers: 0,
+                reputation: T::ReputationPolicy::reputation(
+                    &Default::default(),
+                    now.saturating_sub(old.created_at),
+                ),
+                reputation_stats: Default::default(),
+                status: ArtistStatus::Active,
+            };
+            Artists::<T, I>::insert(&who, record);
+            accounts.push(who);
+        }
+
+        // The artists beyond `T::MaxArtists` keep their record but are left out of the set,
+        // the runtime should not lower the bound below the registered artists
+        Pallet::<T, I>::notify_artists_changed(&accounts, &[]);
+
+        // Likewise for the candidates beyond `T::MaxCandidates`
+        let mut candidates: Vec<T::AccountId> = Candidates::<T, I>::iter_keys().collect();
+        let candidate_count = candidates.len() as u64;
+        candidates.sort();
+        candidates.truncate(T::MaxCandidates::get() as usize);
+        SortedCandidates::<T, I>::put(BoundedVec::truncate_from(candidates));
+
+        StorageVersion::new(1).put::<Pallet<T, I>>();
+
+        // Per artist: the old entry, the new record with its counter and the identifier
+        T::DbWeight::get().reads_writes(
+            migrated
+                .saturating_mul(2)
+                .saturating_add(candidate_count)
+                .saturating_add(3),
+            migrated.saturating_mul(4).saturating_add(3),
+        )
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let count = v0::Artists::<T, I>::iter_keys().count() as u32;
+        frame_support::ensure!(
+            count <= T::MaxArtists::get(),
+            "More artists than `MaxArtists` are registered"
+        );
+        frame_support::ensure!(
+            Candidates::<T, I>::iter_keys().count() as u32 <= T::MaxCandidates::get(),
+            "More candidates than `MaxCandidates` are registered"
+        );
+        Ok(count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let count = u32::decode(&mut &state[..]).map_err(|_| "Invalid pre upgrade state")?;
+        frame_support::ensure!(
+            Artists::<T, I>::count() == count,
+            "The artist count does not match the migrated artists"
+        );
+        frame_support::ensure!(
+            SortedArtists::<T, I>::decode_len().unwrap_or(0) as u32 == count,
+            "The sorted artists do not match the migrated artists"
+        );
+        frame_support::ensure!(
+            Pallet::<T, I>::on_chain_storage_version() >= 1,
+            "The storage version was not updated"
+        );
+        Ok(())
+    }
+}
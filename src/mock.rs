@@ -1,20 +1,26 @@
 use crate::{
     self as pallet_artists,
     tests::{ALICE, BOB},
+    traits::{ArtistCollectionFactory, FungibleTokens, LinearReputation},
+    GenesisArtist,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 
-use frame_support::traits::AsEnsureOriginWithArg;
+use frame_support::traits::{ChangeMembers, EnsureOrigin, EnsureOriginWithArg};
 use frame_support::{
-    construct_runtime, parameter_types,
-    traits::{ConstU32, ConstU64},
+    construct_runtime,
+    instances::Instance1,
+    ord_parameter_types, parameter_types,
+    traits::{ConstU32, ConstU64, ConstU8},
+    weights::{FixedFee, Weight},
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use pallet_transaction_payment::CurrencyAdapter;
 use scale_info::TypeInfo;
 use sp_core::{RuntimeDebug, H256};
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    traits::{BlakeTwo256, Convert, IdentityLookup},
+    BuildStorage, DispatchError, Perbill,
 };
 
 #[derive(
@@ -84,6 +90,16 @@ impl pallet_balances::Config for Test {
     type MaxHolds = ();
 }
 
+impl pallet_transaction_payment::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+    type OperationalFeeMultiplier = ConstU8<5>;
+    // Every transaction costs 1 for its weight and 1 for its length on top of the base fee
+    type WeightToFee = FixedFee<1, u64>;
+    type LengthToFee = FixedFee<1, u64>;
+    type FeeMultiplierUpdate = ();
+}
+
 impl pallet_assets::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Balance = u64;
@@ -91,7 +107,7 @@ impl pallet_assets::Config for Test {
     type AssetId = u32;
     type AssetIdParameter = u32;
     type Currency = Balances;
-    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+    type CreateOrigin = EnsureSignedOutsideOf<FAN_TOKEN_BASE>;
     type ForceOrigin = EnsureRoot<u64>;
     type AssetDeposit = ConstU64<1>;
     type AssetAccountDeposit = ConstU64<10>;
@@ -105,22 +121,249 @@ impl pallet_assets::Config for Test {
     type WeightInfo = ();
 }
 
+impl pallet_identity::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BasicDeposit = ConstU64<10>;
+    type FieldDeposit = ConstU64<1>;
+    type SubAccountDeposit = ConstU64<1>;
+    type MaxSubAccounts = ConstU32<2>;
+    type MaxAdditionalFields = ConstU32<2>;
+    type MaxRegistrars = ConstU32<2>;
+    type Slashed = ();
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type RegistrarOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
 parameter_types! {
     // We use small max values for testing purpose
     pub const CreationDepositAmount: u64 = 10;
     pub const MaxArtists: u32 = 5;
-    pub const MaxCandidates: u32 = 10;
+    pub const MaxCandidates: u32 = 5;
     pub const NameMaxLength: u32 = 20;
+    pub const MetadataMaxLength: u32 = 32;
+    pub const MaxVerifiers: u32 = 3;
+    pub const AttestationQuorum: u32 = 2;
+    pub const EvidenceDeposit: u64 = 2;
+    pub const MaxEndorsements: u32 = 3;
+    pub static EndorsementThreshold: Option<u32> = None;
+    pub const MaxSuspensionsPerBlock: u32 = 2;
+    pub const MaxCandidateCalls: u32 = 2;
+    pub static MaxCandidateCallWeight: Weight = Weight::MAX;
+    pub const CandidateEraLength: u64 = 10;
+    pub const MaxFreeCalls: u32 = 2;
+    pub const FreeCallPeriod: u64 = 10;
+    pub static ApprovalDelay: u64 = 0;
+    pub const MaxApprovalsPerBlock: u32 = 2;
+    pub const ChallengeBond: u64 = 15;
+    pub const ChallengerReward: Perbill = Perbill::from_percent(50);
+    pub const DisputeTimeout: u64 = 10;
+    pub const MaxEvidenceItems: u32 = 3;
+    pub const EvidenceMaxLength: u32 = 32;
+    pub const WorkDeposit: u64 = 2;
+    pub const MaxWorksPerArtist: u32 = 2;
+    pub const TitleMaxLength: u32 = 32;
+    pub const SplitDeposit: u64 = 2;
+    pub const MaxSplitPayees: u32 = 3;
+    pub const MaxSplitsPerArtist: u32 = 2;
+    pub const MaxSplitVersions: u32 = 3;
+    pub const CollaborationDeposit: u64 = 3;
+    pub static FanTokensEnabled: bool = true;
+    pub const FanTokenDeposit: u64 = 4;
+    pub static CollectionsEnabled: bool = true;
+    pub static NextCollection: u32 = 0;
+    pub const MaxCollaborations: u32 = 2;
+    pub const MaxPendingCollaborations: u32 = 3;
+    pub const FollowDeposit: u64 = 1;
+    pub const MaxFollowersReaped: u32 = 2;
+    pub const EndorsementPoints: u32 = 10;
+    pub const DisputePoints: u32 = 5;
+    pub const CallPoints: u32 = 1;
+    pub const AgePeriod: u64 = 100;
+    // Last artist set received through `MembershipChanged`
+    pub static ArtistSet: Vec<AccountId> = vec![];
+}
+
+ord_parameter_types! {
+    pub const VetoAccount: AccountId = 3;
+}
+
+/// The level deposits held on top of the creation deposit
+pub struct TestLevelDeposit;
+impl Convert<pallet_artists::VerificationLevel, u64> for TestLevelDeposit {
+    fn convert(level: pallet_artists::VerificationLevel) -> u64 {
+        match level {
+            pallet_artists::VerificationLevel::Emerging => 0,
+            pallet_artists::VerificationLevel::Verified => 5,
+            pallet_artists::VerificationLevel::CertifiedLabel => 20,
+        }
+    }
+}
+
+/// The asset identifiers from which the fan tokens are issued.
+pub const FAN_TOKEN_BASE: u32 = 1000;
+
+/// The fan tokens get the asset identifiers following `BASE`, when enabled
+pub struct TestFanTokenId<const BASE: u32>;
+impl<const BASE: u32> Convert<pallet_artists::FanTokenIndex, Option<u32>> for TestFanTokenId<BASE> {
+    fn convert(index: pallet_artists::FanTokenIndex) -> Option<u32> {
+        FanTokensEnabled::get().then(|| BASE + index)
+    }
+}
+
+/// Any signed account creates assets, except from the identifiers reserved to the fan tokens
+pub struct EnsureSignedOutsideOf<const BASE: u32>;
+impl<const BASE: u32> EnsureOriginWithArg<RuntimeOrigin, u32> for EnsureSignedOutsideOf<BASE> {
+    type Success = AccountId;
+
+    fn try_origin(o: RuntimeOrigin, asset: &u32) -> Result<AccountId, RuntimeOrigin> {
+        if *asset >= BASE {
+            return Err(o);
+        }
+        frame_system::EnsureSigned::<AccountId>::try_origin(o)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_asset: &u32) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(0))
+    }
+}
+
+/// The collections are numbered in their creation order, when enabled
+pub struct TestCollections;
+impl ArtistCollectionFactory<AccountId> for TestCollections {
+    type CollectionId = u32;
+
+    fn create_collection(_artist: &AccountId) -> Result<u32, DispatchError> {
+        if !CollectionsEnabled::get() {
+            return Err(DispatchError::Unavailable);
+        }
+        let collection = NextCollection::get();
+        NextCollection::set(collection + 1);
+        Ok(collection)
+    }
+}
+
+/// Keep track of the last artist set notified by the pallet.
+pub struct TestChangeMembers;
+impl ChangeMembers<AccountId> for TestChangeMembers {
+    fn change_members_sorted(
+        _incoming: &[AccountId],
+        _outgoing: &[AccountId],
+        sorted_new: &[AccountId],
+    ) {
+        ArtistSet::set(sorted_new.to_vec());
+    }
 }
 
 impl pallet_artists::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type Slashed = ();
+    type Origin = RuntimeOrigin;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type VetoOrigin = EnsureSignedBy<VetoAccount, AccountId>;
+    type Call = RuntimeCall;
+    type CreationDepositAmount = CreationDepositAmount;
+    type EvidenceDeposit = EvidenceDeposit;
+    type MaxEvidenceItems = MaxEvidenceItems;
+    type EvidenceMaxLength = EvidenceMaxLength;
+    type LevelDeposit = TestLevelDeposit;
+    type NameMaxLength = NameMaxLength;
+    type MetadataMaxLength = MetadataMaxLength;
+    type MaxArtists = MaxArtists;
+    type MaxCandidates = MaxCandidates;
+    type MaxVerifiers = MaxVerifiers;
+    type AttestationQuorum = AttestationQuorum;
+    type MaxEndorsements = MaxEndorsements;
+    type EndorsementThreshold = EndorsementThreshold;
+    type MaxCandidateCalls = MaxCandidateCalls;
+    type MaxCandidateCallWeight = MaxCandidateCallWeight;
+    type CandidateEraLength = CandidateEraLength;
+    type MaxFreeCalls = MaxFreeCalls;
+    type FreeCallPeriod = FreeCallPeriod;
+    type ApprovalDelay = ApprovalDelay;
+    type MaxApprovalsPerBlock = MaxApprovalsPerBlock;
+    type MaxSuspensionsPerBlock = MaxSuspensionsPerBlock;
+    type ChallengeBond = ChallengeBond;
+    type ChallengerReward = ChallengerReward;
+    type DisputeTimeout = DisputeTimeout;
+    type WorkDeposit = WorkDeposit;
+    type MaxWorksPerArtist = MaxWorksPerArtist;
+    type TitleMaxLength = TitleMaxLength;
+    type SplitDeposit = SplitDeposit;
+    type MaxSplitPayees = MaxSplitPayees;
+    type MaxSplitsPerArtist = MaxSplitsPerArtist;
+    type MaxSplitVersions = MaxSplitVersions;
+    type CollaborationDeposit = CollaborationDeposit;
+    type MaxCollaborations = MaxCollaborations;
+    type MaxPendingCollaborations = MaxPendingCollaborations;
+    type Assets = FungibleTokens<Assets>;
+    type FanTokenId = TestFanTokenId<FAN_TOKEN_BASE>;
+    type FanTokenDeposit = FanTokenDeposit;
+    type FollowDeposit = FollowDeposit;
+    type MaxFollowersReaped = MaxFollowersReaped;
+    type Collections = TestCollections;
+    type Identity = Identity;
+    type ReputationPolicy =
+        LinearReputation<EndorsementPoints, DisputePoints, CallPoints, AgePeriod>;
+    type MembershipChanged = TestChangeMembers;
+    type WeightInfo = ();
+}
+
+// A second registry sharing the same workflow, used to ensure instances are isolated
+impl pallet_artists::Config<Instance1> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Slashed = ();
     type Origin = RuntimeOrigin;
     type AdminOrigin = EnsureRoot<AccountId>;
+    type VetoOrigin = EnsureSignedBy<VetoAccount, AccountId>;
     type Call = RuntimeCall;
     type CreationDepositAmount = CreationDepositAmount;
+    type EvidenceDeposit = EvidenceDeposit;
+    type MaxEvidenceItems = MaxEvidenceItems;
+    type EvidenceMaxLength = EvidenceMaxLength;
+    type LevelDeposit = TestLevelDeposit;
     type NameMaxLength = NameMaxLength;
+    type MetadataMaxLength = MetadataMaxLength;
+    type MaxArtists = MaxArtists;
+    type MaxCandidates = MaxCandidates;
+    type MaxVerifiers = MaxVerifiers;
+    type AttestationQuorum = AttestationQuorum;
+    type MaxEndorsements = MaxEndorsements;
+    type EndorsementThreshold = EndorsementThreshold;
+    type MaxCandidateCalls = MaxCandidateCalls;
+    type MaxCandidateCallWeight = MaxCandidateCallWeight;
+    type CandidateEraLength = CandidateEraLength;
+    type MaxFreeCalls = MaxFreeCalls;
+    type FreeCallPeriod = FreeCallPeriod;
+    type ApprovalDelay = ApprovalDelay;
+    type MaxApprovalsPerBlock = MaxApprovalsPerBlock;
+    type MaxSuspensionsPerBlock = MaxSuspensionsPerBlock;
+    type ChallengeBond = ChallengeBond;
+    type ChallengerReward = ChallengerReward;
+    type DisputeTimeout = DisputeTimeout;
+    type WorkDeposit = WorkDeposit;
+    type MaxWorksPerArtist = MaxWorksPerArtist;
+    type TitleMaxLength = TitleMaxLength;
+    type SplitDeposit = SplitDeposit;
+    type MaxSplitPayees = MaxSplitPayees;
+    type MaxSplitsPerArtist = MaxSplitsPerArtist;
+    type MaxSplitVersions = MaxSplitVersions;
+    type CollaborationDeposit = CollaborationDeposit;
+    type MaxCollaborations = MaxCollaborations;
+    type MaxPendingCollaborations = MaxPendingCollaborations;
+    type Assets = ();
+    type FanTokenId = ();
+    type FanTokenDeposit = FanTokenDeposit;
+    type FollowDeposit = FollowDeposit;
+    type MaxFollowersReaped = MaxFollowersReaped;
+    type Collections = ();
+    type Identity = ();
+    type ReputationPolicy = ();
+    type MembershipChanged = ();
     type WeightInfo = ();
 }
 
@@ -129,12 +372,37 @@ construct_runtime!(
     {
         System: frame_system,
         Balances: pallet_balances,
+        TransactionPayment: pallet_transaction_payment,
         Assets: pallet_assets,
+        Identity: pallet_identity,
         ArtistsPallet: pallet_artists,
+        ProducersPallet: pallet_artists::<Instance1>,
     }
 );
 
 pub(crate) fn new_test_ext(include_genesis: bool) -> sp_io::TestExternalities {
+    let artists_config: pallet_artists::GenesisConfig<Test> = match include_genesis {
+        true => pallet_artists::GenesisConfig {
+            artists: vec![GenesisArtist {
+                account: ALICE,
+                name: "Genesis Alice".into(),
+                metadata: vec![],
+                level: Default::default(),
+                deposit_exempt: false,
+                id: None,
+            }],
+            candidates: vec![(BOB, "Genesis Bob".into())],
+            ..Default::default()
+        },
+        false => pallet_artists::GenesisConfig::default(),
+    };
+
+    new_test_ext_with_genesis(artists_config)
+}
+
+pub(crate) fn new_test_ext_with_genesis(
+    artists_config: pallet_artists::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
     let mut storage = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
@@ -148,14 +416,6 @@ pub(crate) fn new_test_ext(include_genesis: bool) -> sp_io::TestExternalities {
             .collect(),
     };
 
-    let artists_config: pallet_artists::GenesisConfig<Test> = match include_genesis {
-        true => pallet_artists::GenesisConfig {
-            artists: vec![(ALICE, "Genesis Alice".into())],
-            candidates: vec![(BOB, "Genesis Bob".into())],
-        },
-        false => pallet_artists::GenesisConfig::default(),
-    };
-
     config.assimilate_storage(&mut storage).unwrap();
     artists_config.assimilate_storage(&mut storage).unwrap();
 
@@ -0,0 +1,26 @@
+//! Runtime API definition for the artists pallet.
+
+use super::*;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Query the state of the artists registry.
+    pub trait ArtistsApi<AccountId, BlockNumber, CollectionId>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        CollectionId: Codec,
+    {
+        /// What the candidate could still dispatch through `call_as_candidate` in the
+        /// ongoing era, `None` if it isn't a candidate.
+        fn candidate_call_budget(candidate: AccountId) -> Option<CallBudget<BlockNumber>>;
+
+        /// The verified NFT collection of the artist, `None` if it isn't an active
+        /// artist or has no collection.
+        fn verified_collection_of(artist: AccountId) -> Option<CollectionId>;
+
+        /// The reputation of the artist as of its last update, `None` if it isn't an
+        /// artist.
+        fn reputation_of(artist: AccountId) -> Option<Reputation>;
+    }
+}
@@ -1,15 +1,23 @@
 use super::*;
 use crate::{
     mock::{RuntimeOrigin, *},
+    traits::RoyaltySplitProvider,
     Event::*,
 };
-use allfeat_support::types::actors::artist::{ArtistData, CandidateData};
+use allfeat_support::traits::actors::{artist::ArtistStorage, ActorStorage};
+use allfeat_support::types::actors::artist::CandidateData;
 use rand::{thread_rng, Rng};
 
+use frame_support::dispatch::GetDispatchInfo;
+use frame_support::instances::Instance1;
+use frame_support::traits::{fungibles, Contains, Currency, EnsureOrigin, Hooks, SortedMembers};
 use frame_support::{assert_noop, assert_ok, ensure};
 use frame_system::pallet_prelude::BlockNumberFor;
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-use sp_runtime::traits::BadOrigin;
+use pallet_identity::{Data, IdentityInfo, Judgement};
+use sp_core::H256;
+use sp_runtime::traits::{BadOrigin, Dispatchable, SignedExtension};
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
 
 type AccountId = <Test as frame_system::Config>::AccountId;
 
@@ -17,6 +25,7 @@ type AccountId = <Test as frame_system::Config>::AccountId;
 pub const ALICE: AccountId = 0; // Root, Artist
 pub const BOB: AccountId = 1; // Candidate
 pub const JOHN: AccountId = 2; // Nothing
+pub const VERIFIERS: [AccountId; 3] = [10, 11, 12]; // Verifiers
 
 /// Helper function that generates a random string from a given length
 /// Should only be used for testing purpose
@@ -36,11 +45,14 @@ fn assert_last_event(event: pallet::Event<Test>) {
     System::assert_last_event(mock::RuntimeEvent::ArtistsPallet(event))
 }
 
-impl<T: Config> Pallet<T> {
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
     /// Simple extrinsic that success if the caller is a certified artist
     pub fn test_caller_is_artist(origin: OriginFor<T>) -> DispatchResult {
         let caller = ensure_signed(origin)?;
-        ensure!(Artists::<T>::contains_key(&caller), Error::<T>::NotAnArtist);
+        ensure!(
+            Artists::<T, I>::contains_key(&caller),
+            Error::<T, I>::NotAnArtist
+        );
         Ok(())
     }
 
@@ -48,8 +60,8 @@ impl<T: Config> Pallet<T> {
     pub fn test_caller_is_candidate(origin: OriginFor<T>) -> DispatchResult {
         let caller = ensure_signed(origin)?;
         ensure!(
-            Candidates::<T>::contains_key(&caller),
-            Error::<T>::NotACandidate
+            Candidates::<T, I>::contains_key(&caller),
+            Error::<T, I>::NotACandidate
         );
         Ok(())
     }
@@ -62,16 +74,33 @@ fn test_genesis_config() {
         // Test genesis from artists:
         // ==========================
         let artist = ArtistsPallet::get_artist(ALICE).unwrap();
-        let expected_artist: ArtistData<
-            BoundedVec<u8, <Test as Config>::NameMaxLength>,
-            BlockNumberFor<Test>,
-        > = ArtistData {
+        let expected_artist: ArtistOf<Test> = ArtistRecord {
+            id: 0,
             name: b"Genesis Alice".to_vec().try_into().unwrap(),
+            metadata: Default::default(),
             created_at: 0,
+            level: VerificationLevel::Emerging,
+            deposit: CreationDepositAmount::get(),
+            deposit_exempt: false,
+            fan_token: None,
+            collection: None,
+            followers: 0,
+            reputation: 0,
+            reputation_stats: Default::default(),
+            status: ArtistStatus::Active,
         };
 
+        assert_eq!(artist.id, expected_artist.id);
         assert_eq!(artist.name, expected_artist.name);
+        assert_eq!(artist.metadata, expected_artist.metadata);
         assert_eq!(artist.created_at, expected_artist.created_at);
+        assert_eq!(artist.level, expected_artist.level);
+        assert_eq!(artist.deposit, expected_artist.deposit);
+        assert_eq!(artist.deposit_exempt, expected_artist.deposit_exempt);
+        assert_eq!(artist.fan_token, expected_artist.fan_token);
+        assert_eq!(artist.followers, expected_artist.followers);
+        assert_eq!(artist.reputation, expected_artist.reputation);
+        assert_eq!(artist.status, expected_artist.status);
 
         // Ensure that the deposit is also effected in the genesis build
         let deposit = CreationDepositAmount::get();
@@ -105,6 +134,196 @@ fn test_genesis_config() {
     });
 }
 
+/// A genesis artist holding the creation deposit of an emerging artist
+fn genesis_artist(account: AccountId, name: &str) -> GenesisArtist<AccountId> {
+    GenesisArtist {
+        account,
+        name: name.into(),
+        metadata: vec![],
+        level: VerificationLevel::Emerging,
+        deposit_exempt: false,
+        id: None,
+    }
+}
+
+#[test]
+fn test_genesis_config_with_artist_profiles() {
+    let config = crate::GenesisConfig::<Test> {
+        artists: vec![
+            GenesisArtist {
+                metadata: b"ipfs://alice".to_vec(),
+                level: VerificationLevel::Verified,
+                id: Some(7),
+                ..genesis_artist(ALICE, "Alice")
+            },
+            GenesisArtist {
+                deposit_exempt: true,
+                ..genesis_artist(JOHN, "John")
+            },
+        ],
+        ..Default::default()
+    };
+
+    new_test_ext_with_genesis(config).execute_with(|| {
+        let alice = ArtistsPallet::get_artist(ALICE).unwrap();
+        assert_eq!(alice.id, 7);
+        assert_eq!(alice.metadata.to_vec(), b"ipfs://alice".to_vec());
+        assert_eq!(alice.level, VerificationLevel::Verified);
+        assert_eq!(alice.deposit, CreationDepositAmount::get() + 5);
+        assert_eq!(Balances::reserved_balance(ALICE), alice.deposit);
+
+        // The artists without identifier are numbered after the highest given one
+        let john = ArtistsPallet::get_artist(JOHN).unwrap();
+        assert_eq!(john.id, 8);
+        assert_eq!(john.deposit, 0);
+        assert!(john.deposit_exempt);
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+
+        assert_eq!(ArtistsPallet::get_artist_account(7), Some(ALICE));
+        assert_eq!(ArtistsPallet::get_artist_account(8), Some(JOHN));
+        assert_eq!(ArtistsPallet::next_artist_id(), 9);
+
+        // The genesis artists are reported to the membership listeners
+        let mut members = vec![ALICE, JOHN];
+        members.sort();
+        assert_eq!(ArtistsPallet::active_artists().to_vec(), members);
+        assert_eq!(ArtistSet::get(), members);
+
+        // The next approved candidate follows the genesis artists
+        assert_ok!(ArtistsPallet::submit_candidacy(
+            RuntimeOrigin::signed(BOB),
+            b"Bob".to_vec()
+        ));
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_eq!(ArtistsPallet::get_artist(BOB).unwrap().id, 9);
+    });
+}
+
+#[test]
+fn test_genesis_config_validation() {
+    new_test_ext(false).execute_with(|| {
+        let too_long = generate_random_string(NameMaxLength::get() as usize + 1);
+        let invalid_configs = vec![
+            (
+                vec![genesis_artist(ALICE, &too_long)],
+                vec![],
+                GenesisError::NameTooLong(ALICE),
+            ),
+            (
+                vec![],
+                vec![(BOB, too_long.clone().into())],
+                GenesisError::NameTooLong(BOB),
+            ),
+            (
+                vec![GenesisArtist {
+                    metadata: vec![0; MetadataMaxLength::get() as usize + 1],
+                    ..genesis_artist(ALICE, "Alice")
+                }],
+                vec![],
+                GenesisError::MetadataTooLong(ALICE),
+            ),
+            (
+                vec![
+                    genesis_artist(ALICE, "Alice"),
+                    genesis_artist(ALICE, "Other"),
+                ],
+                vec![],
+                GenesisError::DuplicateArtist(ALICE),
+            ),
+            (
+                vec![],
+                vec![(BOB, "Bob".into()), (BOB, "Other".into())],
+                GenesisError::DuplicateCandidate(BOB),
+            ),
+            (
+                vec![genesis_artist(ALICE, "Alice")],
+                vec![(ALICE, "Alice".into())],
+                GenesisError::ArtistAndCandidate(ALICE),
+            ),
+            (
+                vec![
+                    GenesisArtist {
+                        id: Some(3),
+                        ..genesis_artist(ALICE, "Alice")
+                    },
+                    GenesisArtist {
+                        id: Some(3),
+                        ..genesis_artist(JOHN, "John")
+                    },
+                ],
+                vec![],
+                GenesisError::DuplicateArtistId(3),
+            ),
+            (
+                (0..=MaxArtists::get() as u64)
+                    .map(|account| genesis_artist(account, "Artist"))
+                    .collect(),
+                vec![],
+                GenesisError::TooManyArtists,
+            ),
+            (
+                vec![],
+                (0..=MaxCandidates::get() as u64)
+                    .map(|account| (account, "Candidate".into()))
+                    .collect(),
+                GenesisError::TooManyCandidates,
+            ),
+            // Only the 100 first accounts are funded
+            (
+                vec![genesis_artist(100, "Broke")],
+                vec![],
+                GenesisError::InsufficientBalance(100),
+            ),
+            (
+                vec![],
+                vec![(100, "Broke".into())],
+                GenesisError::InsufficientBalance(100),
+            ),
+            // The level deposit is held on top of the creation deposit
+            (
+                vec![GenesisArtist {
+                    level: VerificationLevel::CertifiedLabel,
+                    ..genesis_artist(ALICE, "Alice")
+                }],
+                vec![],
+                GenesisError::InsufficientBalance(ALICE),
+            ),
+        ];
+
+        // Drain Alice balance for the level deposit case
+        Balances::make_free_balance_be(&ALICE, CreationDepositAmount::get() + 10);
+
+        for (artists, candidates, error) in invalid_configs {
+            let config = crate::GenesisConfig::<Test> {
+                artists,
+                candidates,
+                ..Default::default()
+            };
+            assert_eq!(config.validate(), Err(error));
+        }
+
+        // Deposit exempt artists don't need any balance
+        let config = crate::GenesisConfig::<Test> {
+            artists: vec![GenesisArtist {
+                deposit_exempt: true,
+                ..genesis_artist(100, "Legacy")
+            }],
+            ..Default::default()
+        };
+        assert_ok!(config.validate());
+    });
+}
+
+#[test]
+#[should_panic(expected = "Invalid artists genesis config")]
+fn test_genesis_config_build_fails_on_invalid_config() {
+    new_test_ext_with_genesis(crate::GenesisConfig::<Test> {
+        artists: vec![genesis_artist(ALICE, "Alice")],
+        candidates: vec![(ALICE, "Alice".into())],
+        ..Default::default()
+    });
+}
+
 #[test]
 fn test_submit_candidacy_with_too_long_name() {
     new_test_ext(true).execute_with(|| {
@@ -191,7 +410,12 @@ fn test_submit_candidacy() {
             RuntimeOrigin::signed(JOHN)
         ));
 
-        assert_last_event(CandidateAdded(JOHN));
+        assert_last_event(CandidateAdded {
+            candidate: JOHN,
+            name: b"Johnny".to_vec().try_into().unwrap(),
+            deposit,
+            created_at: System::block_number(),
+        });
     });
 }
 
@@ -218,7 +442,11 @@ fn test_withdraw_candidacy() {
             Error::<Test>::NotACandidate
         );
 
-        assert_last_event(CandidateWithdrew(BOB));
+        assert_last_event(CandidateWithdrew {
+            candidate: BOB,
+            depositor: BOB,
+            deposit: CreationDepositAmount::get(),
+        });
     });
 }
 
@@ -240,7 +468,12 @@ fn test_approve_candidacy_to_artist() {
         // Root could approve an artist
         assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
 
-        assert_last_event(CandidateApproved(BOB));
+        assert_last_event(CandidateApproved {
+            candidate: BOB,
+            name: b"Genesis Bob".to_vec().try_into().unwrap(),
+            deposit: CreationDepositAmount::get(),
+            source: ApprovalSource::Admin,
+        });
 
         // Could not approve an artist twice
         assert_noop!(
@@ -292,3 +525,2993 @@ fn test_caller_is_candidate() {
         );
     })
 }
+
+#[test]
+fn test_membership_sets() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::submit_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            b"Johnny".to_vec().try_into().unwrap()
+        ));
+
+        assert!(<ArtistMembers<Test> as Contains<_>>::contains(&ALICE));
+        assert!(!<ArtistMembers<Test> as Contains<_>>::contains(&BOB));
+        assert_eq!(ArtistMembers::<Test>::sorted_members(), vec![ALICE]);
+
+        assert!(<CandidateMembers<Test> as Contains<_>>::contains(&BOB));
+        assert!(!<CandidateMembers<Test> as Contains<_>>::contains(&ALICE));
+        assert_eq!(CandidateMembers::<Test>::sorted_members(), vec![BOB, JOHN]);
+
+        // Only certified artists are actors
+        assert!(ArtistsPallet::is_actor(&ALICE));
+        assert!(!ArtistsPallet::is_actor(&BOB));
+        assert!(!ArtistsPallet::is_actor(&JOHN));
+    })
+}
+
+#[test]
+fn test_artist_set_is_bounded() {
+    new_test_ext(true).execute_with(|| {
+        // Fill the registry up to `MaxArtists`, one artist being suspended
+        for artist in 4..8 {
+            assert_ok!(ArtistsPallet::force_register_artist(
+                RuntimeOrigin::root(),
+                artist,
+                profile("Artist"),
+                true
+            ));
+        }
+        assert_ok!(ArtistsPallet::suspend_artist(
+            RuntimeOrigin::root(),
+            4,
+            10,
+            SuspensionReason::Other
+        ));
+        assert_eq!(
+            ArtistsPallet::active_artists().to_vec(),
+            vec![ALICE, 5, 6, 7]
+        );
+        assert_eq!(ArtistSet::get(), vec![ALICE, 5, 6, 7]);
+
+        // The suspended artists still count
+        assert_noop!(
+            ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB),
+            Error::<Test>::TooManyArtists
+        );
+        assert_noop!(
+            ArtistsPallet::force_register_artist(
+                RuntimeOrigin::root(),
+                JOHN,
+                profile("John"),
+                true
+            ),
+            Error::<Test>::TooManyArtists
+        );
+
+        assert_ok!(ArtistsPallet::unsuspend_artist(RuntimeOrigin::root(), 4));
+        assert_eq!(
+            ArtistsPallet::active_artists().to_vec(),
+            vec![ALICE, 4, 5, 6, 7]
+        );
+        assert_eq!(ArtistSet::get(), vec![ALICE, 4, 5, 6, 7]);
+    })
+}
+
+#[test]
+fn test_candidate_set_is_bounded() {
+    new_test_ext(true).execute_with(|| {
+        // Fill the candidates up to `MaxCandidates`, Bob being a genesis candidate
+        for candidate in 20..24 {
+            assert_ok!(ArtistsPallet::submit_candidacy(
+                RuntimeOrigin::signed(candidate),
+                b"Candidate".to_vec()
+            ));
+        }
+        assert_eq!(
+            ArtistsPallet::current_candidates().to_vec(),
+            vec![BOB, 20, 21, 22, 23]
+        );
+        assert_noop!(
+            ArtistsPallet::submit_candidacy(RuntimeOrigin::signed(JOHN), b"John".to_vec()),
+            Error::<Test>::TooManyCandidates
+        );
+        assert_noop!(
+            ArtistsPallet::sponsor_candidacy(RuntimeOrigin::signed(ALICE), JOHN, b"John".to_vec()),
+            Error::<Test>::TooManyCandidates
+        );
+
+        // Leaving the candidates makes room
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_ok!(ArtistsPallet::withdraw_candidacy(RuntimeOrigin::signed(21)));
+        assert_eq!(CandidateMembers::<Test>::sorted_members(), vec![20, 22, 23]);
+        assert_ok!(ArtistsPallet::submit_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            b"John".to_vec()
+        ));
+        assert_eq!(
+            CandidateMembers::<Test>::sorted_members(),
+            vec![JOHN, 20, 22, 23]
+        );
+    })
+}
+
+#[test]
+fn test_approve_candidacy_notifies_membership_change() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+
+        assert_eq!(ArtistSet::get(), vec![ALICE, BOB]);
+        assert_eq!(ArtistMembers::<Test>::sorted_members(), vec![ALICE, BOB]);
+        assert_eq!(
+            CandidateMembers::<Test>::sorted_members(),
+            Vec::<AccountId>::new()
+        );
+    })
+}
+
+#[test]
+fn test_instances_are_isolated() {
+    new_test_ext(true).execute_with(|| {
+        // The genesis only applies to the default instance
+        assert!(!ProducersPallet::is_artist(&ALICE));
+        assert!(!ProducersPallet::is_candidate(&BOB));
+
+        assert_ok!(ProducersPallet::submit_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            b"Johnny".to_vec().try_into().unwrap()
+        ));
+        System::assert_last_event(mock::RuntimeEvent::ProducersPallet(Event::<
+            Test,
+            Instance1,
+        >::CandidateAdded {
+            candidate: JOHN,
+            name: b"Johnny".to_vec().try_into().unwrap(),
+            deposit: CreationDepositAmount::get(),
+            created_at: System::block_number(),
+        }));
+
+        // A candidate of an instance isn't one of the other instance
+        assert!(ProducersPallet::is_candidate(&JOHN));
+        assert!(!ArtistsPallet::is_candidate(&JOHN));
+
+        assert_ok!(ProducersPallet::approve_candidacy(
+            RuntimeOrigin::root(),
+            JOHN
+        ));
+        assert!(ProducersPallet::is_artist(&JOHN));
+        assert!(!ArtistsPallet::is_artist(&JOHN));
+
+        // JOHN could still candidate as an artist
+        assert_ok!(ArtistsPallet::submit_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            b"Johnny".to_vec().try_into().unwrap()
+        ));
+
+        // Origins of an instance are refused by the other instance
+        let origin: mock::RuntimeOrigin = RawOrigin::<AccountId, ()>::Artist(ALICE).into();
+        assert!(EnsureArtist::<Test, Instance1>::try_origin(origin.clone()).is_err());
+        assert_eq!(EnsureArtist::<Test>::try_origin(origin).ok(), Some(ALICE));
+    })
+}
+
+/// Register the test verifiers, each one charging the given fee
+fn add_verifiers(fee: u64) {
+    for verifier in VERIFIERS {
+        assert_ok!(ArtistsPallet::add_verifier(
+            RuntimeOrigin::root(),
+            verifier,
+            fee
+        ));
+    }
+}
+
+#[test]
+fn test_manage_verifiers() {
+    new_test_ext(true).execute_with(|| {
+        // Only the admin could add a verifier
+        assert_noop!(
+            ArtistsPallet::add_verifier(RuntimeOrigin::signed(JOHN), JOHN, 5),
+            BadOrigin
+        );
+
+        add_verifiers(5);
+        assert_last_event(VerifierAdded {
+            verifier: VERIFIERS[2],
+            fee: 5,
+        });
+
+        assert_noop!(
+            ArtistsPallet::add_verifier(RuntimeOrigin::root(), VERIFIERS[0], 5),
+            Error::<Test>::AlreadyAVerifier
+        );
+        assert_noop!(
+            ArtistsPallet::add_verifier(RuntimeOrigin::root(), JOHN, 5),
+            Error::<Test>::TooManyVerifiers
+        );
+
+        assert_ok!(ArtistsPallet::set_verifier_fee(
+            RuntimeOrigin::signed(VERIFIERS[0]),
+            8
+        ));
+        assert_eq!(ArtistsPallet::get_verifier_fee(VERIFIERS[0]), Some(8));
+        assert_noop!(
+            ArtistsPallet::set_verifier_fee(RuntimeOrigin::signed(JOHN), 8),
+            Error::<Test>::NotAVerifier
+        );
+
+        assert_ok!(ArtistsPallet::remove_verifier(
+            RuntimeOrigin::root(),
+            VERIFIERS[0]
+        ));
+        assert_last_event(VerifierRemoved {
+            verifier: VERIFIERS[0],
+        });
+        assert!(!ArtistsPallet::is_verifier(&VERIFIERS[0]));
+        assert_noop!(
+            ArtistsPallet::remove_verifier(RuntimeOrigin::root(), VERIFIERS[0]),
+            Error::<Test>::VerifierNotFound
+        );
+    })
+}
+
+#[test]
+fn test_request_attestation() {
+    new_test_ext(true).execute_with(|| {
+        add_verifiers(5);
+        let reserve = Balances::reserved_balance(BOB);
+
+        // Only candidates could request an attestation
+        assert_noop!(
+            ArtistsPallet::request_attestation(RuntimeOrigin::signed(JOHN), VERIFIERS[0], 5),
+            Error::<Test>::NotACandidate
+        );
+        assert_noop!(
+            ArtistsPallet::request_attestation(RuntimeOrigin::signed(BOB), JOHN, 5),
+            Error::<Test>::VerifierNotFound
+        );
+        assert_noop!(
+            ArtistsPallet::request_attestation(RuntimeOrigin::signed(BOB), VERIFIERS[0], 4),
+            Error::<Test>::FeeTooHigh
+        );
+
+        assert_ok!(ArtistsPallet::request_attestation(
+            RuntimeOrigin::signed(BOB),
+            VERIFIERS[0],
+            5
+        ));
+        assert_last_event(AttestationRequested {
+            candidate: BOB,
+            verifier: VERIFIERS[0],
+            fee: 5,
+        });
+        assert_eq!(Balances::reserved_balance(BOB), reserve + 5);
+
+        assert_noop!(
+            ArtistsPallet::request_attestation(RuntimeOrigin::signed(BOB), VERIFIERS[0], 5),
+            Error::<Test>::AttestationAlreadyRequested
+        );
+
+        // The fee is released on cancellation
+        assert_ok!(ArtistsPallet::cancel_attestation_request(
+            RuntimeOrigin::signed(BOB),
+            VERIFIERS[0]
+        ));
+        assert_eq!(Balances::reserved_balance(BOB), reserve);
+        assert_noop!(
+            ArtistsPallet::cancel_attestation_request(RuntimeOrigin::signed(BOB), VERIFIERS[0]),
+            Error::<Test>::AttestationNotRequested
+        );
+
+        // The fees are released when the candidacy is withdrawn
+        assert_ok!(ArtistsPallet::request_attestation(
+            RuntimeOrigin::signed(BOB),
+            VERIFIERS[1],
+            5
+        ));
+        assert_ok!(ArtistsPallet::withdraw_candidacy(RuntimeOrigin::signed(
+            BOB
+        )));
+        assert_eq!(Balances::reserved_balance(BOB), 0);
+        assert_eq!(
+            ArtistsPallet::get_attestation_request(BOB, VERIFIERS[1]),
+            None
+        );
+    })
+}
+
+#[test]
+fn test_attestation_quorum_promotes_candidate() {
+    new_test_ext(true).execute_with(|| {
+        add_verifiers(5);
+        for verifier in VERIFIERS {
+            assert_ok!(ArtistsPallet::request_attestation(
+                RuntimeOrigin::signed(BOB),
+                verifier,
+                5
+            ));
+        }
+
+        // A verifier could only attest a requested candidacy
+        assert_noop!(
+            ArtistsPallet::attest_candidacy(
+                RuntimeOrigin::signed(VERIFIERS[0]),
+                JOHN,
+                Verdict::Approved
+            ),
+            Error::<Test>::AttestationNotRequested
+        );
+        assert_noop!(
+            ArtistsPallet::attest_candidacy(RuntimeOrigin::signed(JOHN), BOB, Verdict::Approved),
+            Error::<Test>::NotAVerifier
+        );
+
+        // Rejections are paid but do not count toward the quorum
+        assert_ok!(ArtistsPallet::attest_candidacy(
+            RuntimeOrigin::signed(VERIFIERS[0]),
+            BOB,
+            Verdict::Rejected
+        ));
+        assert_eq!(Balances::free_balance(VERIFIERS[0]), 100 + 5);
+        assert_ok!(ArtistsPallet::attest_candidacy(
+            RuntimeOrigin::signed(VERIFIERS[1]),
+            BOB,
+            Verdict::Approved
+        ));
+        assert_eq!(ArtistsPallet::approvals_of(&BOB), 1);
+        assert!(ArtistsPallet::is_candidate(&BOB));
+
+        // The quorum is reached
+        assert_ok!(ArtistsPallet::attest_candidacy(
+            RuntimeOrigin::signed(VERIFIERS[2]),
+            BOB,
+            Verdict::Approved
+        ));
+        assert_last_event(CandidateApproved {
+            candidate: BOB,
+            name: b"Genesis Bob".to_vec().try_into().unwrap(),
+            deposit: CreationDepositAmount::get(),
+            source: ApprovalSource::Attestations,
+        });
+        assert!(ArtistsPallet::is_artist(&BOB));
+        assert_eq!(ArtistsPallet::get_attestation(BOB, VERIFIERS[2]), None);
+
+        // Only the artist deposit is still held
+        assert_eq!(
+            Balances::reserved_balance(BOB),
+            CreationDepositAmount::get()
+        );
+        assert_eq!(
+            Balances::free_balance(BOB),
+            100 - CreationDepositAmount::get() - 3 * 5
+        );
+    })
+}
+
+#[test]
+fn test_approvals_of_removed_verifiers_are_ignored() {
+    new_test_ext(true).execute_with(|| {
+        add_verifiers(5);
+        for verifier in &VERIFIERS[..2] {
+            assert_ok!(ArtistsPallet::request_attestation(
+                RuntimeOrigin::signed(BOB),
+                *verifier,
+                5
+            ));
+        }
+        assert_ok!(ArtistsPallet::attest_candidacy(
+            RuntimeOrigin::signed(VERIFIERS[0]),
+            BOB,
+            Verdict::Approved
+        ));
+        assert_eq!(ArtistsPallet::approvals_of(&BOB), 1);
+
+        // The verdict of a removed verifier is kept but no longer counts
+        assert_ok!(ArtistsPallet::remove_verifier(
+            RuntimeOrigin::root(),
+            VERIFIERS[0]
+        ));
+        assert_eq!(
+            ArtistsPallet::get_attestation(BOB, VERIFIERS[0]),
+            Some(Verdict::Approved)
+        );
+        assert_eq!(ArtistsPallet::approvals_of(&BOB), 0);
+
+        assert_ok!(ArtistsPallet::attest_candidacy(
+            RuntimeOrigin::signed(VERIFIERS[1]),
+            BOB,
+            Verdict::Approved
+        ));
+        assert_eq!(ArtistsPallet::approvals_of(&BOB), 1);
+        assert!(ArtistsPallet::is_candidate(&BOB));
+    })
+}
+
+#[test]
+fn test_set_verification_level() {
+    new_test_ext(true).execute_with(|| {
+        let deposit = CreationDepositAmount::get();
+        add_verifiers(5);
+
+        // Only the admin or a verifier could change a level
+        assert_noop!(
+            ArtistsPallet::set_verification_level(
+                RuntimeOrigin::signed(JOHN),
+                ALICE,
+                VerificationLevel::Verified
+            ),
+            Error::<Test>::NotAVerifier
+        );
+        assert_noop!(
+            ArtistsPallet::set_verification_level(
+                RuntimeOrigin::root(),
+                BOB,
+                VerificationLevel::Verified
+            ),
+            Error::<Test>::ArtistNotFound
+        );
+        assert_noop!(
+            ArtistsPallet::set_verification_level(
+                RuntimeOrigin::root(),
+                ALICE,
+                VerificationLevel::Emerging
+            ),
+            Error::<Test>::SameVerificationLevel
+        );
+
+        // Upgrading holds the level deposit
+        assert_ok!(ArtistsPallet::set_verification_level(
+            RuntimeOrigin::signed(VERIFIERS[0]),
+            ALICE,
+            VerificationLevel::CertifiedLabel
+        ));
+        assert_last_event(VerificationLevelChanged {
+            artist: ALICE,
+            previous: VerificationLevel::Emerging,
+            level: VerificationLevel::CertifiedLabel,
+            deposit: deposit + 20,
+            verifier: Some(VERIFIERS[0]),
+        });
+        assert_eq!(Balances::reserved_balance(ALICE), deposit + 20);
+        assert_eq!(
+            ArtistsPallet::get_artist(ALICE).unwrap().deposit,
+            deposit + 20
+        );
+
+        // Downgrading releases the difference
+        assert_ok!(ArtistsPallet::set_verification_level(
+            RuntimeOrigin::root(),
+            ALICE,
+            VerificationLevel::Verified
+        ));
+        assert_eq!(Balances::reserved_balance(ALICE), deposit + 5);
+        assert_eq!(
+            ArtistsPallet::verification_level_of(&ALICE),
+            Some(VerificationLevel::Verified)
+        );
+    })
+}
+
+#[test]
+fn test_attestation_quorum_with_failing_approval() {
+    new_test_ext(true).execute_with(|| {
+        // Fill the registry up to `MaxArtists`
+        for artist in 4..8 {
+            assert_ok!(ArtistsPallet::submit_candidacy(
+                RuntimeOrigin::signed(artist),
+                b"Artist".to_vec()
+            ));
+            assert_ok!(ArtistsPallet::approve_candidacy(
+                RuntimeOrigin::root(),
+                artist
+            ));
+        }
+        add_verifiers(5);
+        for verifier in &VERIFIERS[..2] {
+            assert_ok!(ArtistsPallet::request_attestation(
+                RuntimeOrigin::signed(BOB),
+                *verifier,
+                5
+            ));
+        }
+
+        assert_ok!(ArtistsPallet::attest_candidacy(
+            RuntimeOrigin::signed(VERIFIERS[0]),
+            BOB,
+            Verdict::Approved
+        ));
+        // The verdict reaching the quorum is given and paid even if the approval fails
+        assert_ok!(ArtistsPallet::attest_candidacy(
+            RuntimeOrigin::signed(VERIFIERS[1]),
+            BOB,
+            Verdict::Approved
+        ));
+        assert_last_event(ApprovalFailed {
+            candidate: BOB,
+            error: Error::<Test>::TooManyArtists.into(),
+        });
+        assert_eq!(ArtistsPallet::approvals_of(&BOB), 2);
+        assert_eq!(Balances::free_balance(VERIFIERS[1]), 100 + 5);
+        assert!(ArtistsPallet::is_candidate(&BOB));
+        assert!(!ArtistsPallet::is_artist(&BOB));
+    });
+}
+
+#[test]
+fn test_set_verification_level_without_enough_funds() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            Balances::free_balance(ALICE) - 10
+        ));
+
+        assert_noop!(
+            ArtistsPallet::set_verification_level(
+                RuntimeOrigin::root(),
+                ALICE,
+                VerificationLevel::CertifiedLabel
+            ),
+            Error::<Test>::NotEnoughFunds
+        );
+    })
+}
+
+#[test]
+fn test_add_candidacy_evidence() {
+    new_test_ext(true).execute_with(|| {
+        let deposit = CreationDepositAmount::get();
+        let evidence_deposit = EvidenceDeposit::get();
+        let link: EvidenceOf<Test> =
+            Evidence::Link(b"https://bob.music".to_vec().try_into().unwrap());
+
+        // Only candidates could attach evidence
+        assert_noop!(
+            ArtistsPallet::add_candidacy_evidence(RuntimeOrigin::signed(JOHN), link.clone()),
+            Error::<Test>::NotACandidate
+        );
+
+        assert_ok!(ArtistsPallet::add_candidacy_evidence(
+            RuntimeOrigin::signed(BOB),
+            link.clone()
+        ));
+        assert_last_event(CandidacyEvidenceAdded {
+            candidate: BOB,
+            evidence: link.clone(),
+            deposit: evidence_deposit,
+        });
+        assert_ok!(ArtistsPallet::add_candidacy_evidence(
+            RuntimeOrigin::signed(BOB),
+            Evidence::Document(H256::repeat_byte(1))
+        ));
+        assert_ok!(ArtistsPallet::add_candidacy_evidence(
+            RuntimeOrigin::signed(BOB),
+            Evidence::Statement(b"I am Bob".to_vec().try_into().unwrap())
+        ));
+        assert_eq!(ArtistsPallet::get_candidacy_evidence(BOB).len(), 3);
+        assert_eq!(
+            Balances::reserved_balance(BOB),
+            deposit + 3 * evidence_deposit
+        );
+
+        assert_noop!(
+            ArtistsPallet::add_candidacy_evidence(RuntimeOrigin::signed(BOB), link),
+            Error::<Test>::TooManyEvidenceItems
+        );
+
+        // The evidence deposits are released on approval
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert!(ArtistsPallet::get_candidacy_evidence(BOB).is_empty());
+        assert_eq!(Balances::reserved_balance(BOB), deposit);
+    })
+}
+
+#[test]
+fn test_reject_candidacy() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::add_candidacy_evidence(
+            RuntimeOrigin::signed(BOB),
+            Evidence::Document(H256::repeat_byte(1))
+        ));
+
+        assert_noop!(
+            ArtistsPallet::reject_candidacy(RuntimeOrigin::signed(ALICE), BOB),
+            BadOrigin
+        );
+        assert_noop!(
+            ArtistsPallet::reject_candidacy(RuntimeOrigin::root(), JOHN),
+            Error::<Test>::CandidateNotFound
+        );
+
+        assert_ok!(ArtistsPallet::reject_candidacy(RuntimeOrigin::root(), BOB));
+        assert_last_event(CandidateRejected {
+            candidate: BOB,
+            depositor: BOB,
+            deposit: CreationDepositAmount::get(),
+        });
+
+        // Every deposit is released
+        assert!(!ArtistsPallet::is_candidate(&BOB));
+        assert!(ArtistsPallet::get_candidacy_evidence(BOB).is_empty());
+        assert_eq!(Balances::reserved_balance(BOB), 0);
+        assert_eq!(Balances::free_balance(BOB), 100);
+    })
+}
+
+#[test]
+fn test_challenge_artist() {
+    new_test_ext(true).execute_with(|| {
+        let bond = ChallengeBond::get();
+        let evidence_hash = H256::repeat_byte(1);
+
+        assert_noop!(
+            ArtistsPallet::challenge_artist(RuntimeOrigin::signed(JOHN), BOB, evidence_hash),
+            Error::<Test>::ArtistNotFound
+        );
+
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            ALICE,
+            evidence_hash
+        ));
+        assert_last_event(ArtistChallenged {
+            artist: ALICE,
+            challenger: JOHN,
+            evidence_hash,
+            bond,
+        });
+        assert_eq!(Balances::reserved_balance(JOHN), bond);
+
+        assert_noop!(
+            ArtistsPallet::challenge_artist(RuntimeOrigin::signed(BOB), ALICE, evidence_hash),
+            Error::<Test>::DisputeAlreadyOpened
+        );
+    })
+}
+
+#[test]
+fn test_uphold_dispute_revokes_artist() {
+    new_test_ext(true).execute_with(|| {
+        let deposit = CreationDepositAmount::get();
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            ALICE,
+            H256::repeat_byte(1)
+        ));
+
+        assert_noop!(
+            ArtistsPallet::resolve_dispute(RuntimeOrigin::signed(BOB), ALICE, true),
+            BadOrigin
+        );
+        assert_noop!(
+            ArtistsPallet::resolve_dispute(RuntimeOrigin::root(), BOB, true),
+            Error::<Test>::DisputeNotFound
+        );
+
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            ALICE,
+            true
+        ));
+        assert_last_event(DisputeUpheld {
+            artist: ALICE,
+            challenger: JOHN,
+            reward: deposit / 2,
+        });
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(ArtistRevoked {
+            artist: ALICE,
+            name: b"Genesis Alice".to_vec().try_into().unwrap(),
+            deposit,
+            previous_status: ArtistStatus::Active,
+        }));
+        assert!(!ArtistsPallet::is_artist(&ALICE));
+        assert_eq!(ArtistsPallet::get_dispute(ALICE), None);
+        assert!(ArtistSet::get().is_empty());
+
+        // The bond is released and half of the artist deposit awarded, the rest is slashed
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+        assert_eq!(Balances::free_balance(JOHN), 100 + deposit / 2);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 100 - deposit);
+    })
+}
+
+#[test]
+fn test_dismiss_dispute_slashes_challenger() {
+    new_test_ext(true).execute_with(|| {
+        let bond = ChallengeBond::get();
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            ALICE,
+            H256::repeat_byte(1)
+        ));
+
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            ALICE,
+            false
+        ));
+        assert_last_event(DisputeDismissed {
+            artist: ALICE,
+            challenger: JOHN,
+            slashed: bond,
+        });
+        assert!(ArtistsPallet::is_artist(&ALICE));
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+        assert_eq!(Balances::free_balance(JOHN), 100 - bond);
+    })
+}
+
+#[test]
+fn test_close_expired_dispute() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            ALICE,
+            H256::repeat_byte(1)
+        ));
+
+        System::set_block_number(DisputeTimeout::get());
+        assert_noop!(
+            ArtistsPallet::close_expired_dispute(RuntimeOrigin::signed(BOB), ALICE),
+            Error::<Test>::DisputeNotExpired
+        );
+
+        System::set_block_number(1 + DisputeTimeout::get());
+        assert_ok!(ArtistsPallet::close_expired_dispute(
+            RuntimeOrigin::signed(BOB),
+            ALICE
+        ));
+        assert_last_event(DisputeExpired {
+            artist: ALICE,
+            challenger: JOHN,
+        });
+        assert!(ArtistsPallet::is_artist(&ALICE));
+        assert_eq!(Balances::free_balance(JOHN), 100);
+    })
+}
+
+#[test]
+fn test_endorse_candidate() {
+    new_test_ext(true).execute_with(|| {
+        // Only artists could endorse
+        assert_noop!(
+            ArtistsPallet::endorse_candidate(RuntimeOrigin::signed(JOHN), BOB),
+            Error::<Test>::NotAnArtist
+        );
+        assert_noop!(
+            ArtistsPallet::endorse_candidate(RuntimeOrigin::signed(ALICE), JOHN),
+            Error::<Test>::CandidateNotFound
+        );
+
+        assert_ok!(ArtistsPallet::endorse_candidate(
+            RuntimeOrigin::signed(ALICE),
+            BOB
+        ));
+        assert_last_event(CandidateEndorsed {
+            candidate: BOB,
+            artist: ALICE,
+            endorsements: 1,
+        });
+        assert_eq!(ArtistsPallet::get_endorsements(BOB).to_vec(), vec![ALICE]);
+
+        // An artist endorses a candidate once
+        assert_noop!(
+            ArtistsPallet::endorse_candidate(RuntimeOrigin::signed(ALICE), BOB),
+            Error::<Test>::AlreadyEndorsed
+        );
+
+        // Without threshold, the candidate still waits for the admin
+        assert!(ArtistsPallet::is_candidate(&BOB));
+
+        // The endorsements are cleared with the candidacy
+        assert_ok!(ArtistsPallet::withdraw_candidacy(RuntimeOrigin::signed(
+            BOB
+        )));
+        assert!(ArtistsPallet::get_endorsements(BOB).is_empty());
+    })
+}
+
+#[test]
+fn test_endorsement_threshold_approves_candidate() {
+    new_test_ext(true).execute_with(|| {
+        EndorsementThreshold::set(Some(2));
+
+        assert_ok!(ArtistsPallet::submit_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            b"Johnny".to_vec().try_into().unwrap()
+        ));
+        assert_ok!(ArtistsPallet::approve_candidacy(
+            RuntimeOrigin::root(),
+            JOHN
+        ));
+
+        assert_ok!(ArtistsPallet::endorse_candidate(
+            RuntimeOrigin::signed(ALICE),
+            BOB
+        ));
+        assert!(ArtistsPallet::is_candidate(&BOB));
+
+        assert_ok!(ArtistsPallet::endorse_candidate(
+            RuntimeOrigin::signed(JOHN),
+            BOB
+        ));
+        assert_last_event(CandidateApproved {
+            candidate: BOB,
+            name: b"Genesis Bob".to_vec().try_into().unwrap(),
+            deposit: CreationDepositAmount::get(),
+            source: ApprovalSource::Endorsements,
+        });
+        assert!(ArtistsPallet::is_artist(&BOB));
+        assert!(ArtistsPallet::get_endorsements(BOB).is_empty());
+    })
+}
+
+#[test]
+fn test_suspend_artist() {
+    new_test_ext(true).execute_with(|| {
+        assert_noop!(
+            ArtistsPallet::suspend_artist(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                10,
+                SuspensionReason::CompromisedKey
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            ArtistsPallet::suspend_artist(
+                RuntimeOrigin::root(),
+                BOB,
+                10,
+                SuspensionReason::CompromisedKey
+            ),
+            Error::<Test>::ArtistNotFound
+        );
+        assert_noop!(
+            ArtistsPallet::suspend_artist(
+                RuntimeOrigin::root(),
+                ALICE,
+                1,
+                SuspensionReason::CompromisedKey
+            ),
+            Error::<Test>::InvalidSuspensionEnd
+        );
+
+        assert_ok!(ArtistsPallet::suspend_artist(
+            RuntimeOrigin::root(),
+            ALICE,
+            10,
+            SuspensionReason::CompromisedKey
+        ));
+        assert_last_event(ArtistSuspended {
+            artist: ALICE,
+            until: 10,
+            reason: SuspensionReason::CompromisedKey,
+        });
+
+        // The record is kept but the artist is inactive
+        assert!(ArtistsPallet::get_artist(ALICE).is_some());
+        assert!(!ArtistsPallet::is_artist(&ALICE));
+        assert!(!<ArtistsPallet as ArtistStorage<_, _, _>>::is_artist(
+            &ALICE
+        ));
+        assert!(ArtistSet::get().is_empty());
+        assert_noop!(
+            ArtistsPallet::call_as_artist(
+                RuntimeOrigin::signed(ALICE),
+                Box::new(frame_system::Call::<Test>::remark { remark: vec![] }.into())
+            ),
+            Error::<Test>::ArtistSuspended
+        );
+        let origin: mock::RuntimeOrigin = RawOrigin::<AccountId, ()>::Artist(ALICE).into();
+        assert!(EnsureArtist::<Test>::try_origin(origin).is_err());
+
+        // A suspended artist could not candidate again
+        assert_noop!(
+            ArtistsPallet::submit_candidacy(
+                RuntimeOrigin::signed(ALICE),
+                b"Alice".to_vec().try_into().unwrap()
+            ),
+            Error::<Test>::AlreadyAnArtist
+        );
+
+        // The suspension could be lifted by the admin
+        assert_ok!(ArtistsPallet::unsuspend_artist(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+        assert_last_event(ArtistUnsuspended {
+            artist: ALICE,
+            until: 10,
+            early: true,
+        });
+        assert!(ArtistsPallet::is_artist(&ALICE));
+        assert_eq!(ArtistSet::get(), vec![ALICE]);
+        assert!(ArtistsPallet::get_suspension_expiries(10).is_empty());
+        assert_noop!(
+            ArtistsPallet::unsuspend_artist(RuntimeOrigin::root(), ALICE),
+            Error::<Test>::ArtistNotSuspended
+        );
+    })
+}
+
+#[test]
+fn test_suspension_is_lifted_automatically() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::suspend_artist(
+            RuntimeOrigin::root(),
+            ALICE,
+            10,
+            SuspensionReason::CopyrightDispute
+        ));
+        // Suspending again moves the end of the suspension
+        assert_ok!(ArtistsPallet::suspend_artist(
+            RuntimeOrigin::root(),
+            ALICE,
+            5,
+            SuspensionReason::CopyrightDispute
+        ));
+        assert!(ArtistsPallet::get_suspension_expiries(10).is_empty());
+
+        System::set_block_number(5);
+        ArtistsPallet::on_initialize(5);
+
+        assert_last_event(ArtistUnsuspended {
+            artist: ALICE,
+            until: 5,
+            early: false,
+        });
+        assert!(ArtistsPallet::is_artist(&ALICE));
+        assert_eq!(ArtistSet::get(), vec![ALICE]);
+        assert!(ArtistsPallet::get_suspension_expiries(5).is_empty());
+    })
+}
+
+#[test]
+fn test_approval_is_enacted_after_delay() {
+    new_test_ext(true).execute_with(|| {
+        ApprovalDelay::set(10);
+        System::set_block_number(1);
+
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_last_event(ApprovalScheduled {
+            candidate: BOB,
+            enact_at: 11,
+            source: ApprovalSource::Admin,
+        });
+        assert!(ArtistsPallet::is_candidate(&BOB));
+        assert!(!ArtistsPallet::is_registered_artist(&BOB));
+        assert_eq!(
+            ArtistsPallet::get_pending_approval(BOB),
+            Some((11, ApprovalSource::Admin))
+        );
+        assert_eq!(ArtistsPallet::get_approval_queue(11).to_vec(), vec![BOB]);
+        assert_noop!(
+            ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB),
+            Error::<Test>::ApprovalAlreadyScheduled
+        );
+
+        System::set_block_number(11);
+        ArtistsPallet::on_initialize(11);
+
+        assert_last_event(ApprovalEnacted { candidate: BOB });
+        assert!(ArtistsPallet::is_artist(&BOB));
+        assert!(!ArtistsPallet::is_candidate(&BOB));
+        assert_eq!(ArtistSet::get(), vec![ALICE, BOB]);
+        assert_eq!(ArtistsPallet::get_pending_approval(BOB), None);
+        assert!(ArtistsPallet::get_approval_queue(11).is_empty());
+    })
+}
+
+#[test]
+fn test_veto_approval() {
+    new_test_ext(true).execute_with(|| {
+        ApprovalDelay::set(10);
+        System::set_block_number(1);
+
+        assert_noop!(
+            ArtistsPallet::veto_approval(RuntimeOrigin::root(), BOB),
+            Error::<Test>::ApprovalNotScheduled
+        );
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+
+        // Only the admin or the veto origin could veto
+        assert_noop!(
+            ArtistsPallet::veto_approval(RuntimeOrigin::signed(JOHN), BOB),
+            BadOrigin
+        );
+        assert_ok!(ArtistsPallet::veto_approval(
+            RuntimeOrigin::signed(VetoAccount::get()),
+            BOB
+        ));
+        assert_last_event(ApprovalVetoed { candidate: BOB });
+        assert!(ArtistsPallet::is_candidate(&BOB));
+        assert_eq!(ArtistsPallet::get_pending_approval(BOB), None);
+        assert!(ArtistsPallet::get_approval_queue(11).is_empty());
+
+        // The candidate could be approved again, and vetoed by the admin
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_ok!(ArtistsPallet::veto_approval(RuntimeOrigin::root(), BOB));
+
+        System::set_block_number(11);
+        ArtistsPallet::on_initialize(11);
+        assert!(!ArtistsPallet::is_registered_artist(&BOB));
+    })
+}
+
+#[test]
+fn test_withdraw_candidacy_cancels_scheduled_approval() {
+    new_test_ext(true).execute_with(|| {
+        ApprovalDelay::set(10);
+        System::set_block_number(1);
+
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_ok!(ArtistsPallet::withdraw_candidacy(RuntimeOrigin::signed(
+            BOB
+        )));
+
+        assert_eq!(ArtistsPallet::get_pending_approval(BOB), None);
+        assert!(ArtistsPallet::get_approval_queue(11).is_empty());
+    })
+}
+
+#[test]
+fn test_sponsor_candidacy() {
+    new_test_ext(true).execute_with(|| {
+        let deposit = CreationDepositAmount::get();
+
+        assert_noop!(
+            ArtistsPallet::sponsor_candidacy(
+                RuntimeOrigin::signed(JOHN),
+                BOB,
+                b"Bob".to_vec().try_into().unwrap()
+            ),
+            Error::<Test>::AlreadyACandidate
+        );
+
+        assert_ok!(ArtistsPallet::sponsor_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            3,
+            b"Sponsored".to_vec().try_into().unwrap()
+        ));
+        assert_last_event(CandidateSponsored {
+            candidate: 3,
+            sponsor: JOHN,
+            deposit,
+        });
+        assert!(ArtistsPallet::is_candidate(&3));
+        assert_eq!(ArtistsPallet::get_sponsor(3), Some(JOHN));
+
+        // The deposit is held from the sponsor only
+        assert_eq!(Balances::reserved_balance(JOHN), deposit);
+        assert_eq!(Balances::reserved_balance(3), 0);
+
+        assert_eq!(ArtistsPallet::get_candidacy_deposit(3), Some(deposit));
+
+        // The sponsor gets the deposit back on withdrawal
+        assert_ok!(ArtistsPallet::withdraw_candidacy(RuntimeOrigin::signed(3)));
+        assert_last_event(CandidateWithdrew {
+            candidate: 3,
+            depositor: JOHN,
+            deposit,
+        });
+        assert_eq!(ArtistsPallet::get_candidacy_deposit(3), None);
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+        assert_eq!(Balances::free_balance(JOHN), 100);
+        assert_eq!(ArtistsPallet::get_sponsor(3), None);
+    })
+}
+
+#[test]
+fn test_reject_sponsored_candidacy_refunds_sponsor() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::sponsor_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            3,
+            b"Sponsored".to_vec().try_into().unwrap()
+        ));
+
+        assert_ok!(ArtistsPallet::reject_candidacy(RuntimeOrigin::root(), 3));
+        assert_last_event(CandidateRejected {
+            candidate: 3,
+            depositor: JOHN,
+            deposit: CreationDepositAmount::get(),
+        });
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+        assert_eq!(ArtistsPallet::get_sponsor(3), None);
+    })
+}
+
+#[test]
+fn test_take_over_sponsored_deposit() {
+    new_test_ext(true).execute_with(|| {
+        let deposit = CreationDepositAmount::get();
+
+        assert_noop!(
+            ArtistsPallet::take_over_deposit(RuntimeOrigin::signed(BOB)),
+            Error::<Test>::NotSponsored
+        );
+
+        assert_ok!(ArtistsPallet::sponsor_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            3,
+            b"Sponsored".to_vec().try_into().unwrap()
+        ));
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), 3));
+        // The sponsor keeps paying for the artist
+        assert_eq!(Balances::reserved_balance(JOHN), deposit);
+        assert_eq!(ArtistsPallet::get_sponsor(3), Some(JOHN));
+
+        assert_ok!(ArtistsPallet::take_over_deposit(RuntimeOrigin::signed(3)));
+        assert_last_event(SponsorshipEnded {
+            who: 3,
+            sponsor: JOHN,
+            deposit,
+        });
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+        assert_eq!(Balances::reserved_balance(3), deposit);
+        assert_eq!(ArtistsPallet::get_sponsor(3), None);
+    })
+}
+
+#[test]
+fn test_uphold_dispute_slashes_sponsor() {
+    new_test_ext(true).execute_with(|| {
+        let deposit = CreationDepositAmount::get();
+
+        assert_ok!(ArtistsPallet::sponsor_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            3,
+            b"Sponsored".to_vec().try_into().unwrap()
+        ));
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), 3));
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(4),
+            3,
+            H256::repeat_byte(1)
+        ));
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            3,
+            true
+        ));
+
+        // The challenger is rewarded from the deposit held by the sponsor
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+        assert_eq!(Balances::free_balance(JOHN), 100 - deposit);
+        assert_eq!(Balances::free_balance(4), 100 + deposit / 2);
+        assert_eq!(ArtistsPallet::get_sponsor(3), None);
+    })
+}
+
+/// Run a call through the fee-less candidacy extension, returning the fee charged to `who`.
+fn dispatch_with_extension(
+    who: AccountId,
+    call: RuntimeCall,
+) -> Result<u64, TransactionValidityError> {
+    let info = call.get_dispatch_info();
+    let len = 10;
+    let balance_before = Balances::total_balance(&who);
+
+    let ext = FreeCandidacyExtension::from(pallet_transaction_payment::ChargeTransactionPayment::<
+        Test,
+    >::from(0));
+    ext.validate(&who, &call, &info, len)?;
+    let pre = ext.pre_dispatch(&who, &call, &info, len)?;
+    let result = call.dispatch(RuntimeOrigin::signed(who));
+    let post_info = match &result {
+        Ok(post_info) => *post_info,
+        Err(e) => e.post_info,
+    };
+    FreeCandidacyExtension::post_dispatch(
+        Some(pre),
+        &info,
+        &post_info,
+        len,
+        &result.map(|_| ()).map_err(|e| e.error),
+    )?;
+
+    // The deposits held or released by the call don't change the total balance
+    Ok(balance_before - Balances::total_balance(&who))
+}
+
+#[test]
+fn test_candidacy_calls_are_fee_less() {
+    new_test_ext(true).execute_with(|| {
+        System::set_block_number(1);
+        let submit = RuntimeCall::ArtistsPallet(Call::submit_candidacy {
+            name: b"Johnny".to_vec(),
+        });
+        let withdraw = RuntimeCall::ArtistsPallet(Call::withdraw_candidacy {});
+
+        assert_eq!(dispatch_with_extension(JOHN, submit.clone()), Ok(0));
+        assert!(ArtistsPallet::is_candidate(&JOHN));
+        assert_eq!(ArtistsPallet::free_calls_left(&JOHN), 1);
+        assert_eq!(dispatch_with_extension(JOHN, withdraw.clone()), Ok(0));
+        assert!(!ArtistsPallet::is_candidate(&JOHN));
+
+        // The free calls are exhausted, the fees are charged again
+        assert_eq!(ArtistsPallet::free_calls_left(&JOHN), 0);
+        assert_eq!(dispatch_with_extension(JOHN, submit), Ok(3));
+
+        // Until the next period
+        System::set_block_number(11);
+        assert_eq!(ArtistsPallet::free_calls_left(&JOHN), 2);
+        assert_eq!(dispatch_with_extension(JOHN, withdraw), Ok(0));
+        assert_eq!(ArtistsPallet::get_free_calls(JOHN), Some((11, 1)));
+    })
+}
+
+#[test]
+fn test_only_eligible_candidacy_calls_are_fee_less() {
+    new_test_ext(true).execute_with(|| {
+        // Other calls are charged
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        assert_eq!(dispatch_with_extension(JOHN, remark), Ok(3));
+
+        // An artist could not candidate, the fees are charged
+        let submit = RuntimeCall::ArtistsPallet(Call::submit_candidacy {
+            name: b"Alice".to_vec(),
+        });
+        assert_eq!(dispatch_with_extension(ALICE, submit.clone()), Ok(3));
+
+        // An account unable to hold the deposit pays the fees, and has nothing to pay them
+        let submit = RuntimeCall::ArtistsPallet(Call::submit_candidacy {
+            name: b"Nobody".to_vec(),
+        });
+        assert!(!ArtistsPallet::is_free_candidacy_call(
+            &300,
+            &Call::submit_candidacy {
+                name: b"Nobody".to_vec()
+            }
+        ));
+        assert_eq!(
+            dispatch_with_extension(300, submit),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Payment
+            ))
+        );
+        assert_eq!(ArtistsPallet::get_free_calls(ALICE), None);
+    })
+}
+
+#[test]
+fn test_candidacy_failing_identity_policy_is_charged() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::set_identity_policy(
+            RuntimeOrigin::root(),
+            IdentityPolicy {
+                required_judgement: Some((0, IdentityJudgement::KnownGood)),
+                copy_display_name: false,
+            }
+        ));
+        let submit = RuntimeCall::ArtistsPallet(Call::submit_candidacy {
+            name: b"Johnny".to_vec(),
+        });
+
+        // Without the required judgement, the submission fails and the fees are charged
+        assert_eq!(dispatch_with_extension(JOHN, submit.clone()), Ok(3));
+        assert!(!ArtistsPallet::is_candidate(&JOHN));
+        assert_eq!(ArtistsPallet::get_free_calls(JOHN), None);
+
+        judge_identity(JOHN, b"John Doe", Judgement::KnownGood);
+        assert_eq!(dispatch_with_extension(JOHN, submit), Ok(0));
+        assert!(ArtistsPallet::is_candidate(&JOHN));
+    })
+}
+
+#[test]
+fn test_call_as_candidate_is_rate_limited() {
+    new_test_ext(true).execute_with(|| {
+        System::set_block_number(1);
+        let remark = || {
+            Box::new(RuntimeCall::System(frame_system::Call::remark {
+                remark: vec![],
+            }))
+        };
+
+        assert_eq!(ArtistsPallet::candidate_call_budget(&JOHN), None);
+        assert_noop!(
+            ArtistsPallet::call_as_candidate(RuntimeOrigin::signed(JOHN), remark()),
+            Error::<Test>::NotACandidate
+        );
+
+        assert_ok!(ArtistsPallet::call_as_candidate(
+            RuntimeOrigin::signed(BOB),
+            remark()
+        ));
+        assert_ok!(ArtistsPallet::call_as_candidate(
+            RuntimeOrigin::signed(BOB),
+            remark()
+        ));
+        let budget = ArtistsPallet::candidate_call_budget(&BOB).unwrap();
+        assert_eq!(budget.calls, 0);
+        assert_eq!(budget.resets_at, 11);
+        assert_noop!(
+            ArtistsPallet::call_as_candidate(RuntimeOrigin::signed(BOB), remark()),
+            Error::<Test>::CandidateRateLimited
+        );
+
+        // The budget is reset lazily on the next era
+        System::set_block_number(11);
+        assert_eq!(ArtistsPallet::candidate_call_budget(&BOB).unwrap().calls, 2);
+        assert_ok!(ArtistsPallet::call_as_candidate(
+            RuntimeOrigin::signed(BOB),
+            remark()
+        ));
+        assert_eq!(
+            ArtistsPallet::get_candidate_call_usage(BOB)
+                .unwrap()
+                .era_start,
+            11
+        );
+
+        // The usage is cleared with the candidacy
+        assert_ok!(ArtistsPallet::withdraw_candidacy(RuntimeOrigin::signed(
+            BOB
+        )));
+        assert_eq!(ArtistsPallet::get_candidate_call_usage(BOB), None);
+    })
+}
+
+#[test]
+fn test_call_as_candidate_weight_is_limited() {
+    new_test_ext(true).execute_with(|| {
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        MaxCandidateCallWeight::set(call.get_dispatch_info().weight);
+
+        assert_ok!(ArtistsPallet::call_as_candidate(
+            RuntimeOrigin::signed(BOB),
+            Box::new(call.clone())
+        ));
+        assert_eq!(
+            ArtistsPallet::candidate_call_budget(&BOB).unwrap().weight,
+            Weight::zero()
+        );
+        assert_noop!(
+            ArtistsPallet::call_as_candidate(RuntimeOrigin::signed(BOB), Box::new(call)),
+            Error::<Test>::CandidateRateLimited
+        );
+    })
+}
+
+/// A profile for the artists registered by the admin
+fn profile(name: &str) -> ProfileOf<Test> {
+    ArtistProfile {
+        name: name.as_bytes().to_vec().try_into().unwrap(),
+        metadata: b"ipfs://profile".to_vec().try_into().unwrap(),
+    }
+}
+
+#[test]
+fn test_force_register_artist() {
+    new_test_ext(true).execute_with(|| {
+        assert_noop!(
+            ArtistsPallet::force_register_artist(
+                RuntimeOrigin::signed(JOHN),
+                JOHN,
+                profile("John"),
+                false
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            ArtistsPallet::force_register_artist(
+                RuntimeOrigin::root(),
+                ALICE,
+                profile("Alice"),
+                false
+            ),
+            Error::<Test>::AlreadyAnArtist
+        );
+        // Only the 100 first accounts are funded
+        assert_noop!(
+            ArtistsPallet::force_register_artist(
+                RuntimeOrigin::root(),
+                100,
+                profile("Broke"),
+                false
+            ),
+            Error::<Test>::NotEnoughFunds
+        );
+
+        assert_ok!(ArtistsPallet::force_register_artist(
+            RuntimeOrigin::root(),
+            JOHN,
+            profile("John"),
+            false
+        ));
+
+        let artist = ArtistsPallet::get_artist(JOHN).unwrap();
+        assert_eq!(artist.name.to_vec(), b"John".to_vec());
+        assert_eq!(artist.metadata.to_vec(), b"ipfs://profile".to_vec());
+        assert_eq!(artist.deposit, CreationDepositAmount::get());
+        assert!(!artist.deposit_exempt);
+        assert_eq!(
+            Balances::reserved_balance(JOHN),
+            CreationDepositAmount::get()
+        );
+        assert_eq!(ArtistSet::get(), vec![ALICE, JOHN]);
+        assert_last_event(ArtistRegistered {
+            artist: JOHN,
+            id: artist.id,
+            name: artist.name,
+            deposit: CreationDepositAmount::get(),
+            deposit_exempt: false,
+        });
+    });
+}
+
+#[test]
+fn test_force_register_candidate_releases_candidacy() {
+    new_test_ext(true).execute_with(|| {
+        // A sponsored candidate with an evidence attached
+        assert_ok!(ArtistsPallet::sponsor_candidacy(
+            RuntimeOrigin::signed(ALICE),
+            JOHN,
+            b"John".to_vec()
+        ));
+        assert_ok!(ArtistsPallet::add_candidacy_evidence(
+            RuntimeOrigin::signed(JOHN),
+            Evidence::Document(H256::zero())
+        ));
+        let alice_reserve = Balances::reserved_balance(ALICE);
+
+        assert_ok!(ArtistsPallet::force_register_artist(
+            RuntimeOrigin::root(),
+            JOHN,
+            profile("John"),
+            true
+        ));
+
+        assert!(!ArtistsPallet::is_candidate(&JOHN));
+        assert!(ArtistsPallet::is_artist(&JOHN));
+        assert_eq!(ArtistsPallet::get_sponsor(JOHN), None);
+        // The sponsor got the creation deposit back and nothing is held from John
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            alice_reserve - CreationDepositAmount::get()
+        );
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+        assert_eq!(ArtistsPallet::get_candidacy_evidence(JOHN).len(), 0);
+    });
+}
+
+#[test]
+fn test_deposit_exempt_artist_holds_nothing() {
+    new_test_ext(true).execute_with(|| {
+        // Only the 100 first accounts are funded
+        let legacy = 100;
+        assert_ok!(ArtistsPallet::force_register_artist(
+            RuntimeOrigin::root(),
+            legacy,
+            profile("Legacy"),
+            true
+        ));
+        assert_eq!(ArtistsPallet::get_artist(legacy).unwrap().deposit, 0);
+
+        // Leveling up holds no level deposit
+        assert_ok!(ArtistsPallet::set_verification_level(
+            RuntimeOrigin::root(),
+            legacy,
+            VerificationLevel::CertifiedLabel
+        ));
+        assert_eq!(ArtistsPallet::get_artist(legacy).unwrap().deposit, 0);
+        assert_eq!(Balances::reserved_balance(legacy), 0);
+        assert_ok!(ArtistsPallet::set_verification_level(
+            RuntimeOrigin::root(),
+            legacy,
+            VerificationLevel::Emerging
+        ));
+        assert_eq!(Balances::reserved_balance(legacy), 0);
+
+        // Upholding a challenge confiscates nothing
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            legacy,
+            H256::zero()
+        ));
+        let john_balance = Balances::free_balance(JOHN);
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            legacy,
+            true
+        ));
+        assert!(!ArtistsPallet::is_registered_artist(&legacy));
+        assert_eq!(
+            Balances::free_balance(JOHN),
+            john_balance + ChallengeBond::get()
+        );
+        assert_last_event(DisputeUpheld {
+            artist: legacy,
+            challenger: JOHN,
+            reward: 0,
+        });
+    });
+}
+
+/// Propose a collaboration between the given artists, on a work identified by `work`
+fn propose_collaboration(
+    proposer: AccountId,
+    counterparty: AccountId,
+    work: u64,
+) -> DispatchResult {
+    ArtistsPallet::propose_collaboration(
+        RuntimeOrigin::signed(proposer),
+        counterparty,
+        H256::from_low_u64_be(work),
+        b"feat".to_vec().try_into().unwrap(),
+    )
+}
+
+#[test]
+fn test_propose_collaboration() {
+    new_test_ext(true).execute_with(|| {
+        assert_noop!(
+            propose_collaboration(BOB, ALICE, 1),
+            Error::<Test>::NotAnArtist
+        );
+        assert_noop!(
+            propose_collaboration(ALICE, ALICE, 1),
+            Error::<Test>::SelfCollaboration
+        );
+        assert_noop!(
+            propose_collaboration(ALICE, BOB, 1),
+            Error::<Test>::ArtistNotFound
+        );
+
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        assert_ok!(propose_collaboration(ALICE, BOB, 1));
+
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            alice_reserve + CollaborationDeposit::get()
+        );
+        let collaboration = ArtistsPallet::get_collaboration(0).unwrap();
+        assert_eq!(collaboration.counterparty, BOB);
+        assert_eq!(
+            collaboration.status,
+            CollaborationStatus::Proposed { at: 1 }
+        );
+        assert_eq!(ArtistsPallet::next_collaboration_id(), 1);
+        assert_last_event(CollaborationProposed {
+            id: 0,
+            proposer: ALICE,
+            counterparty: BOB,
+            work_hash: H256::from_low_u64_be(1),
+        });
+
+        // Only confirmed collaborations are listed
+        assert!(ArtistsPallet::collaborations_of(&ALICE, 0, 10).is_empty());
+    });
+}
+
+#[test]
+fn test_accept_collaboration() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        for work in 0..3 {
+            assert_ok!(propose_collaboration(ALICE, BOB, work));
+        }
+
+        // Only the counterparty could confirm
+        assert_noop!(
+            ArtistsPallet::accept_collaboration(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::NotACollaborator
+        );
+        assert_noop!(
+            ArtistsPallet::accept_collaboration(RuntimeOrigin::signed(BOB), 3),
+            Error::<Test>::CollaborationNotFound
+        );
+
+        assert_ok!(ArtistsPallet::accept_collaboration(
+            RuntimeOrigin::signed(BOB),
+            1
+        ));
+        assert_last_event(CollaborationConfirmed {
+            id: 1,
+            proposer: ALICE,
+            counterparty: BOB,
+        });
+        assert_noop!(
+            ArtistsPallet::accept_collaboration(RuntimeOrigin::signed(BOB), 1),
+            Error::<Test>::CollaborationAlreadyConfirmed
+        );
+        assert_ok!(ArtistsPallet::accept_collaboration(
+            RuntimeOrigin::signed(BOB),
+            0
+        ));
+
+        // The deposits are released once confirmed
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            alice_reserve + CollaborationDeposit::get()
+        );
+
+        // Listed for both artists by order of confirmation, page by page
+        let ids = |artist, start, limit| {
+            ArtistsPallet::collaborations_of(&artist, start, limit)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(ALICE, 0, 10), vec![1, 0]);
+        assert_eq!(ids(BOB, 0, 10), vec![1, 0]);
+        assert_eq!(ids(BOB, 0, 1), vec![1]);
+        assert_eq!(ids(BOB, 1, 1), vec![0]);
+        assert!(ids(BOB, 2, 1).is_empty());
+
+        assert_noop!(
+            ArtistsPallet::accept_collaboration(RuntimeOrigin::signed(BOB), 2),
+            Error::<Test>::TooManyCollaborations
+        );
+    });
+}
+
+#[test]
+fn test_cancel_collaboration() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        assert_ok!(propose_collaboration(ALICE, BOB, 0));
+        assert_ok!(propose_collaboration(ALICE, BOB, 1));
+
+        assert_noop!(
+            ArtistsPallet::cancel_collaboration(RuntimeOrigin::signed(JOHN), 0),
+            Error::<Test>::NotACollaborator
+        );
+
+        // Either artist could cancel
+        assert_ok!(ArtistsPallet::cancel_collaboration(
+            RuntimeOrigin::signed(ALICE),
+            0
+        ));
+        assert_ok!(ArtistsPallet::cancel_collaboration(
+            RuntimeOrigin::signed(BOB),
+            1
+        ));
+        assert_last_event(CollaborationCancelled {
+            id: 1,
+            cancelled_by: BOB,
+        });
+        assert_eq!(ArtistsPallet::get_collaboration(0), None);
+        assert_eq!(Balances::reserved_balance(ALICE), alice_reserve);
+
+        // A confirmed collaboration stays
+        assert_ok!(propose_collaboration(ALICE, BOB, 2));
+        assert_ok!(ArtistsPallet::accept_collaboration(
+            RuntimeOrigin::signed(BOB),
+            2
+        ));
+        assert_noop!(
+            ArtistsPallet::cancel_collaboration(RuntimeOrigin::signed(ALICE), 2),
+            Error::<Test>::CollaborationAlreadyConfirmed
+        );
+    });
+}
+
+#[test]
+fn test_dissolve_collaboration() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        for work in 0..3 {
+            assert_ok!(propose_collaboration(ALICE, BOB, work));
+        }
+        assert_ok!(ArtistsPallet::accept_collaboration(
+            RuntimeOrigin::signed(BOB),
+            0
+        ));
+        assert_ok!(ArtistsPallet::accept_collaboration(
+            RuntimeOrigin::signed(BOB),
+            1
+        ));
+
+        // Only a confirmed collaboration could be dissolved, by its artists
+        assert_noop!(
+            ArtistsPallet::dissolve_collaboration(RuntimeOrigin::signed(BOB), 2),
+            Error::<Test>::CollaborationNotConfirmed
+        );
+        assert_noop!(
+            ArtistsPallet::dissolve_collaboration(RuntimeOrigin::signed(JOHN), 0),
+            Error::<Test>::NotACollaborator
+        );
+        assert_noop!(
+            ArtistsPallet::dissolve_collaboration(RuntimeOrigin::signed(BOB), 3),
+            Error::<Test>::CollaborationNotFound
+        );
+        assert_noop!(
+            ArtistsPallet::accept_collaboration(RuntimeOrigin::signed(BOB), 2),
+            Error::<Test>::TooManyCollaborations
+        );
+
+        assert_ok!(ArtistsPallet::dissolve_collaboration(
+            RuntimeOrigin::signed(ALICE),
+            0
+        ));
+        assert_last_event(CollaborationDissolved {
+            id: 0,
+            dissolved_by: ALICE,
+        });
+        assert_eq!(ArtistsPallet::get_collaboration(0), None);
+        assert_eq!(
+            ArtistsPallet::get_artist_collaborations(ALICE).to_vec(),
+            vec![1]
+        );
+        assert_eq!(
+            ArtistsPallet::get_artist_collaborations(BOB).to_vec(),
+            vec![1]
+        );
+
+        // The artists could collaborate again
+        assert_ok!(ArtistsPallet::accept_collaboration(
+            RuntimeOrigin::signed(BOB),
+            2
+        ));
+        assert_eq!(
+            ArtistsPallet::get_artist_collaborations(BOB).to_vec(),
+            vec![1, 2]
+        );
+    });
+}
+
+#[test]
+fn test_revocation_dissolves_collaborations() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_ok!(propose_collaboration(ALICE, BOB, 0));
+        assert_ok!(ArtistsPallet::accept_collaboration(
+            RuntimeOrigin::signed(BOB),
+            0
+        ));
+
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            BOB,
+            H256::repeat_byte(1)
+        ));
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            BOB,
+            true
+        ));
+
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(CollaborationDissolved {
+            id: 0,
+            dissolved_by: BOB,
+        }));
+        assert_eq!(ArtistsPallet::get_collaboration(0), None);
+        assert!(ArtistsPallet::get_artist_collaborations(ALICE).is_empty());
+        assert!(ArtistsPallet::get_artist_collaborations(BOB).is_empty());
+    });
+}
+
+#[test]
+fn test_revocation_cancels_pending_collaborations() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        assert_ok!(propose_collaboration(ALICE, BOB, 0));
+        assert_ok!(propose_collaboration(ALICE, BOB, 1));
+        assert_ok!(propose_collaboration(BOB, ALICE, 2));
+        assert_eq!(
+            ArtistsPallet::get_pending_collaborations(ALICE).to_vec(),
+            vec![0, 1, 2]
+        );
+        assert_noop!(
+            propose_collaboration(ALICE, BOB, 3),
+            Error::<Test>::TooManyPendingCollaborations
+        );
+
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            BOB,
+            H256::repeat_byte(1)
+        ));
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            BOB,
+            true
+        ));
+
+        // The proposals to and from the revoked artist are cancelled and refunded
+        for id in 0..3 {
+            System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(CollaborationCancelled {
+                id,
+                cancelled_by: BOB,
+            }));
+            assert_eq!(ArtistsPallet::get_collaboration(id), None);
+        }
+        assert_eq!(Balances::reserved_balance(ALICE), alice_reserve);
+        assert_eq!(Balances::reserved_balance(BOB), 0);
+        assert!(ArtistsPallet::get_pending_collaborations(ALICE).is_empty());
+        assert!(ArtistsPallet::get_pending_collaborations(BOB).is_empty());
+    });
+}
+
+#[test]
+fn test_collaboration_through_call_as_artist() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        let as_artist = |artist: AccountId, call: Call<Test>| {
+            let call: RuntimeCall = call.into();
+            assert_ok!(ArtistsPallet::call_as_artist(
+                RuntimeOrigin::signed(artist),
+                Box::new(call.clone())
+            ));
+            assert_last_event(ArtistExecuted {
+                dispatch_hash: <Test as frame_system::Config>::Hashing::hash_of(&call),
+                result: Ok(()),
+            });
+        };
+
+        as_artist(
+            ALICE,
+            Call::propose_collaboration {
+                counterparty: BOB,
+                work_hash: H256::from_low_u64_be(0),
+                metadata: b"feat".to_vec().try_into().unwrap(),
+            },
+        );
+        as_artist(BOB, Call::accept_collaboration { id: 0 });
+        assert_eq!(
+            ArtistsPallet::get_artist_collaborations(ALICE).to_vec(),
+            vec![0]
+        );
+
+        as_artist(ALICE, Call::dissolve_collaboration { id: 0 });
+        assert_eq!(ArtistsPallet::get_collaboration(0), None);
+    });
+}
+
+/// A work of the catalog, whose ISRC ends with the given digit
+fn work(digit: u8) -> WorkOf<Test> {
+    let mut isrc = *b"FRAFE2400000";
+    isrc[11] = b'0' + digit;
+    Work {
+        title: b"Feat".to_vec().try_into().unwrap(),
+        isrc,
+        iswc: Some(*b"T0345246801"),
+        content_hash: H256::from_low_u64_be(digit as u64),
+        released_at: 1,
+    }
+}
+
+#[test]
+fn test_register_work() {
+    new_test_ext(true).execute_with(|| {
+        assert_noop!(
+            ArtistsPallet::register_work(RuntimeOrigin::signed(BOB), work(0)),
+            Error::<Test>::NotAnArtist
+        );
+        assert_noop!(
+            ArtistsPallet::register_work(
+                RuntimeOrigin::signed(ALICE),
+                Work {
+                    isrc: *b"fr-fe2400000",
+                    ..work(0)
+                }
+            ),
+            Error::<Test>::InvalidWorkCode
+        );
+        assert_noop!(
+            ArtistsPallet::register_work(
+                RuntimeOrigin::signed(ALICE),
+                Work {
+                    iswc: Some(*b"03452468011"),
+                    ..work(0)
+                }
+            ),
+            Error::<Test>::InvalidWorkCode
+        );
+
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        assert_ok!(ArtistsPallet::register_work(
+            RuntimeOrigin::signed(ALICE),
+            work(0)
+        ));
+        assert_last_event(WorkRegistered {
+            artist: ALICE,
+            isrc: work(0).isrc,
+            content_hash: work(0).content_hash,
+            deposit: WorkDeposit::get(),
+        });
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            alice_reserve + WorkDeposit::get()
+        );
+        assert_eq!(
+            ArtistsPallet::work_by_isrc(&work(0).isrc),
+            Some((ALICE, work(0)))
+        );
+
+        // An ISRC is registered once across all the catalogs
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_noop!(
+            ArtistsPallet::register_work(RuntimeOrigin::signed(BOB), work(0)),
+            Error::<Test>::WorkAlreadyRegistered
+        );
+
+        assert_ok!(ArtistsPallet::register_work(
+            RuntimeOrigin::signed(ALICE),
+            work(1)
+        ));
+        assert_noop!(
+            ArtistsPallet::register_work(RuntimeOrigin::signed(ALICE), work(2)),
+            Error::<Test>::TooManyWorks
+        );
+    });
+}
+
+#[test]
+fn test_update_and_retire_work() {
+    new_test_ext(true).execute_with(|| {
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        assert_ok!(ArtistsPallet::register_work(
+            RuntimeOrigin::signed(ALICE),
+            work(0)
+        ));
+
+        let updated = Work {
+            title: b"Feat (Remix)".to_vec().try_into().unwrap(),
+            iswc: None,
+            ..work(0)
+        };
+        assert_noop!(
+            ArtistsPallet::update_work(RuntimeOrigin::signed(ALICE), work(1)),
+            Error::<Test>::WorkNotFound
+        );
+        assert_ok!(ArtistsPallet::update_work(
+            RuntimeOrigin::signed(ALICE),
+            updated.clone()
+        ));
+        assert_last_event(WorkUpdated {
+            artist: ALICE,
+            isrc: updated.isrc,
+        });
+        assert_eq!(
+            ArtistsPallet::work_by_isrc(&updated.isrc),
+            Some((ALICE, updated.clone()))
+        );
+
+        // Only the artist having registered the work could retire it
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_noop!(
+            ArtistsPallet::retire_work(RuntimeOrigin::signed(BOB), updated.isrc),
+            Error::<Test>::WorkNotFound
+        );
+
+        assert_ok!(ArtistsPallet::retire_work(
+            RuntimeOrigin::signed(ALICE),
+            updated.isrc
+        ));
+        assert_last_event(WorkRetired {
+            artist: ALICE,
+            isrc: updated.isrc,
+            deposit: WorkDeposit::get(),
+        });
+        assert_eq!(ArtistsPallet::work_by_isrc(&updated.isrc), None);
+        assert_eq!(Balances::reserved_balance(ALICE), alice_reserve);
+
+        // The ISRC is available again
+        assert_ok!(ArtistsPallet::register_work(
+            RuntimeOrigin::signed(BOB),
+            work(0)
+        ));
+    });
+}
+
+#[test]
+fn test_revoked_artist_catalog_is_cleared() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::register_work(
+            RuntimeOrigin::signed(ALICE),
+            work(0)
+        ));
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            ALICE,
+            H256::zero()
+        ));
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            ALICE,
+            true
+        ));
+
+        assert!(ArtistsPallet::get_catalog(ALICE).is_empty());
+        assert_eq!(ArtistsPallet::get_isrc_owner(work(0).isrc), None);
+        // Only the artist deposit was confiscated, the work deposit was released
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+    });
+}
+
+/// The shares of a royalty split, by percentage
+fn shares(percents: &[(AccountId, u32)]) -> SharesOf<Test> {
+    percents
+        .iter()
+        .map(|&(payee, percent)| (payee, Perbill::from_percent(percent)))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_create_split() {
+    new_test_ext(true).execute_with(|| {
+        assert_noop!(
+            ArtistsPallet::create_split(RuntimeOrigin::signed(BOB), shares(&[(BOB, 100)])),
+            Error::<Test>::NotAnArtist
+        );
+        assert_noop!(
+            ArtistsPallet::create_split(
+                RuntimeOrigin::signed(ALICE),
+                shares(&[(ALICE, 50), (BOB, 40)])
+            ),
+            Error::<Test>::InvalidSplitShares
+        );
+        assert_noop!(
+            ArtistsPallet::create_split(
+                RuntimeOrigin::signed(ALICE),
+                shares(&[(ALICE, 50), (ALICE, 50)])
+            ),
+            Error::<Test>::DuplicatePayee
+        );
+
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        assert_ok!(ArtistsPallet::create_split(
+            RuntimeOrigin::signed(ALICE),
+            shares(&[(ALICE, 70), (BOB, 30)])
+        ));
+        assert_last_event(SplitCreated {
+            id: 0,
+            owner: ALICE,
+            deposit: SplitDeposit::get(),
+        });
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            alice_reserve + SplitDeposit::get()
+        );
+        assert_eq!(
+            <ArtistsPallet as RoyaltySplitProvider<AccountId>>::active_split(0),
+            Some(shares(&[(ALICE, 70), (BOB, 30)]).into_inner())
+        );
+        assert_eq!(
+            <ArtistsPallet as RoyaltySplitProvider<AccountId>>::active_split(1),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_update_and_lock_split() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::create_split(
+            RuntimeOrigin::signed(ALICE),
+            shares(&[(ALICE, 100)])
+        ));
+
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_noop!(
+            ArtistsPallet::update_split(RuntimeOrigin::signed(BOB), 0, shares(&[(BOB, 100)])),
+            Error::<Test>::NotSplitOwner
+        );
+        assert_noop!(
+            ArtistsPallet::update_split(RuntimeOrigin::signed(ALICE), 0, shares(&[(ALICE, 60)])),
+            Error::<Test>::InvalidSplitShares
+        );
+
+        assert_ok!(ArtistsPallet::update_split(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            shares(&[(ALICE, 60), (BOB, 40)])
+        ));
+        assert_last_event(SplitUpdated {
+            id: 0,
+            version: 1,
+            deposit: SplitDeposit::get(),
+        });
+
+        // The previous version stays resolvable
+        assert_eq!(
+            <ArtistsPallet as RoyaltySplitProvider<AccountId>>::active_split(0),
+            Some(shares(&[(ALICE, 60), (BOB, 40)]).into_inner())
+        );
+        assert_eq!(
+            <ArtistsPallet as RoyaltySplitProvider<AccountId>>::split_at(0, 0),
+            Some(shares(&[(ALICE, 100)]).into_inner())
+        );
+        assert_eq!(
+            ArtistsPallet::get_royalty_split(0).unwrap().deposit,
+            2 * SplitDeposit::get()
+        );
+
+        assert_noop!(
+            ArtistsPallet::lock_split(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotSplitOwner
+        );
+        assert_ok!(ArtistsPallet::lock_split(RuntimeOrigin::signed(ALICE), 0));
+        assert_last_event(SplitLocked { id: 0, version: 1 });
+
+        assert_noop!(
+            ArtistsPallet::update_split(RuntimeOrigin::signed(ALICE), 0, shares(&[(ALICE, 100)])),
+            Error::<Test>::SplitLocked
+        );
+        assert_noop!(
+            ArtistsPallet::lock_split(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Test>::SplitLocked
+        );
+    });
+}
+
+#[test]
+fn test_retire_split() {
+    new_test_ext(true).execute_with(|| {
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        assert_ok!(ArtistsPallet::create_split(
+            RuntimeOrigin::signed(ALICE),
+            shares(&[(ALICE, 100)])
+        ));
+        for percent in [60, 70] {
+            assert_ok!(ArtistsPallet::update_split(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                shares(&[(ALICE, percent), (BOB, 100 - percent)])
+            ));
+        }
+        assert_noop!(
+            ArtistsPallet::update_split(RuntimeOrigin::signed(ALICE), 0, shares(&[(ALICE, 100)])),
+            Error::<Test>::TooManySplitVersions
+        );
+        assert_ok!(ArtistsPallet::create_split(
+            RuntimeOrigin::signed(ALICE),
+            shares(&[(ALICE, 100)])
+        ));
+        assert_noop!(
+            ArtistsPallet::create_split(RuntimeOrigin::signed(ALICE), shares(&[(ALICE, 100)])),
+            Error::<Test>::TooManySplits
+        );
+
+        // The deposits are held as long as the versions are stored, even once locked
+        assert_ok!(ArtistsPallet::lock_split(RuntimeOrigin::signed(ALICE), 0));
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            alice_reserve + 4 * SplitDeposit::get()
+        );
+
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        assert_noop!(
+            ArtistsPallet::retire_split(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NotSplitOwner
+        );
+        assert_noop!(
+            ArtistsPallet::retire_split(RuntimeOrigin::signed(ALICE), 2),
+            Error::<Test>::SplitNotFound
+        );
+
+        assert_ok!(ArtistsPallet::retire_split(RuntimeOrigin::signed(ALICE), 0));
+        assert_last_event(SplitRemoved {
+            id: 0,
+            deposit: 3 * SplitDeposit::get(),
+        });
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            alice_reserve + SplitDeposit::get()
+        );
+        assert_eq!(ArtistsPallet::get_royalty_split(0), None);
+        assert_eq!(
+            <ArtistsPallet as RoyaltySplitProvider<AccountId>>::split_at(0, 1),
+            None
+        );
+
+        // The retired split no longer counts toward the limit
+        assert_eq!(ArtistsPallet::get_artist_splits(ALICE).to_vec(), vec![1]);
+        assert_ok!(ArtistsPallet::create_split(
+            RuntimeOrigin::signed(ALICE),
+            shares(&[(ALICE, 100)])
+        ));
+    });
+}
+
+#[test]
+fn test_revocation_removes_splits() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        let bob_reserve = Balances::reserved_balance(BOB);
+        assert_ok!(ArtistsPallet::create_split(
+            RuntimeOrigin::signed(BOB),
+            shares(&[(BOB, 100)])
+        ));
+        assert_ok!(ArtistsPallet::update_split(
+            RuntimeOrigin::signed(BOB),
+            0,
+            shares(&[(BOB, 50), (ALICE, 50)])
+        ));
+        assert_ok!(ArtistsPallet::create_split(
+            RuntimeOrigin::signed(BOB),
+            shares(&[(BOB, 100)])
+        ));
+        assert_eq!(ArtistsPallet::get_artist_splits(BOB).to_vec(), vec![0, 1]);
+        assert_noop!(
+            ArtistsPallet::create_split(RuntimeOrigin::signed(BOB), shares(&[(BOB, 100)])),
+            Error::<Test>::TooManySplits
+        );
+        assert_eq!(
+            Balances::reserved_balance(BOB),
+            bob_reserve + 3 * SplitDeposit::get()
+        );
+
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            BOB,
+            H256::repeat_byte(1)
+        ));
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            BOB,
+            true
+        ));
+
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(SplitRemoved {
+            id: 0,
+            deposit: 2 * SplitDeposit::get(),
+        }));
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(SplitRemoved {
+            id: 1,
+            deposit: SplitDeposit::get(),
+        }));
+        assert_eq!(ArtistsPallet::get_royalty_split(0), None);
+        assert!(ArtistsPallet::get_artist_splits(BOB).is_empty());
+        assert_eq!(
+            <ArtistsPallet as RoyaltySplitProvider<AccountId>>::active_split(0),
+            None
+        );
+        assert_eq!(
+            <ArtistsPallet as RoyaltySplitProvider<AccountId>>::split_at(0, 1),
+            None
+        );
+        // Only the artist deposit was confiscated
+        assert_eq!(Balances::reserved_balance(BOB), 0);
+    });
+}
+
+#[test]
+fn test_create_artist_token() {
+    new_test_ext(true).execute_with(|| {
+        let symbol: BoundedVec<u8, NameMaxLength> = b"GAL".to_vec().try_into().unwrap();
+        assert_noop!(
+            ArtistsPallet::create_artist_token(
+                RuntimeOrigin::signed(BOB),
+                symbol.clone(),
+                10,
+                1_000
+            ),
+            Error::<Test>::NotAnArtist
+        );
+
+        let alice_reserve = Balances::reserved_balance(ALICE);
+        assert_ok!(ArtistsPallet::create_artist_token(
+            RuntimeOrigin::signed(ALICE),
+            symbol.clone(),
+            10,
+            1_000
+        ));
+
+        // The asset identifier derives from the fan token index
+        let asset = FAN_TOKEN_BASE;
+        assert_eq!(ArtistsPallet::next_fan_token(), 1);
+        assert_eq!(
+            ArtistsPallet::get_artist(ALICE).unwrap().fan_token,
+            Some((asset, FanTokenDeposit::get()))
+        );
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            alice_reserve + FanTokenDeposit::get()
+        );
+        assert_last_event(FanTokenCreated {
+            artist: ALICE,
+            asset,
+            supply: 1_000,
+            deposit: FanTokenDeposit::get(),
+        });
+        assert_eq!(Assets::balance(asset, ALICE), 1_000);
+        assert_eq!(
+            <Assets as fungibles::roles::Inspect<AccountId>>::owner(asset),
+            Some(ALICE)
+        );
+        assert_eq!(
+            <Assets as fungibles::metadata::Inspect<AccountId>>::name(asset),
+            b"Genesis Alice".to_vec()
+        );
+        assert_eq!(
+            <Assets as fungibles::metadata::Inspect<AccountId>>::symbol(asset),
+            b"GAL".to_vec()
+        );
+        assert_eq!(
+            <Assets as fungibles::metadata::Inspect<AccountId>>::decimals(asset),
+            10
+        );
+
+        // A single token per artist
+        assert_noop!(
+            ArtistsPallet::create_artist_token(RuntimeOrigin::signed(ALICE), symbol, 10, 1_000),
+            Error::<Test>::FanTokenAlreadyCreated
+        );
+    });
+}
+
+#[test]
+fn test_fan_token_identifiers_are_reserved() {
+    new_test_ext(true).execute_with(|| {
+        // No account could take the identifier of a fan token before the artist
+        assert_noop!(
+            Assets::create(RuntimeOrigin::signed(JOHN), FAN_TOKEN_BASE, JOHN, 1),
+            BadOrigin
+        );
+        assert_ok!(Assets::create(
+            RuntimeOrigin::signed(JOHN),
+            FAN_TOKEN_BASE - 1,
+            JOHN,
+            1
+        ));
+
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+        for (index, artist) in [BOB, ALICE].into_iter().enumerate() {
+            assert_ok!(ArtistsPallet::create_artist_token(
+                RuntimeOrigin::signed(artist),
+                b"TKN".to_vec().try_into().unwrap(),
+                10,
+                1_000
+            ));
+            assert_eq!(
+                ArtistsPallet::get_artist(artist).unwrap().fan_token,
+                Some((FAN_TOKEN_BASE + index as u32, FanTokenDeposit::get()))
+            );
+        }
+
+        // The deposit is released with the artist
+        let bob_reserve = Balances::reserved_balance(BOB);
+        let bob_deposit = ArtistsPallet::get_artist(BOB).unwrap().deposit;
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            BOB,
+            H256::repeat_byte(1)
+        ));
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            BOB,
+            true
+        ));
+        assert_eq!(
+            Balances::reserved_balance(BOB),
+            bob_reserve - bob_deposit - FanTokenDeposit::get()
+        );
+    });
+}
+
+#[test]
+fn test_create_artist_token_when_disabled() {
+    new_test_ext(true).execute_with(|| {
+        FanTokensEnabled::set(false);
+        assert_noop!(
+            ArtistsPallet::create_artist_token(
+                RuntimeOrigin::signed(ALICE),
+                b"GAL".to_vec().try_into().unwrap(),
+                10,
+                1_000
+            ),
+            Error::<Test>::FanTokensDisabled
+        );
+    });
+}
+
+#[test]
+fn test_create_artist_token_without_registry() {
+    new_test_ext(true).execute_with(|| {
+        // The second instance has no assets registry
+        assert_ok!(ProducersPallet::submit_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            b"Johnny".to_vec()
+        ));
+        assert_ok!(ProducersPallet::approve_candidacy(
+            RuntimeOrigin::root(),
+            JOHN
+        ));
+        assert_noop!(
+            ProducersPallet::create_artist_token(
+                RuntimeOrigin::signed(JOHN),
+                b"JHN".to_vec().try_into().unwrap(),
+                10,
+                1_000
+            ),
+            Error::<Test, Instance1>::FanTokensDisabled
+        );
+
+        assert_eq!(
+            <() as ArtistTokenFactory<AccountId>>::create_token(
+                (),
+                &JOHN,
+                b"Johnny".to_vec(),
+                b"JHN".to_vec(),
+                10,
+                1_000
+            ),
+            Err(DispatchError::Unavailable)
+        );
+    });
+}
+
+#[test]
+fn test_approve_candidacy_creates_collection() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+
+        let collection = ArtistsPallet::get_artist(BOB).unwrap().collection;
+        assert_eq!(collection, Some(0));
+        assert_eq!(ArtistsPallet::verified_collection_of(&BOB), Some(0));
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(CollectionCreated {
+            artist: BOB,
+            collection: 0,
+        }));
+
+        // The genesis artists have no collection
+        assert_eq!(ArtistsPallet::verified_collection_of(&ALICE), None);
+    });
+}
+
+#[test]
+fn test_approve_candidacy_without_collection() {
+    new_test_ext(true).execute_with(|| {
+        // The approval doesn't fail when the collection couldn't be created
+        CollectionsEnabled::set(false);
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+
+        assert_eq!(ArtistsPallet::get_artist(BOB).unwrap().collection, None);
+        assert_eq!(ArtistsPallet::verified_collection_of(&BOB), None);
+        assert_noop!(
+            ArtistsPallet::create_artist_collection(RuntimeOrigin::signed(BOB)),
+            DispatchError::Unavailable
+        );
+
+        // The artist creates it once available
+        CollectionsEnabled::set(true);
+        assert_ok!(ArtistsPallet::create_artist_collection(
+            RuntimeOrigin::signed(BOB)
+        ));
+        assert_eq!(ArtistsPallet::verified_collection_of(&BOB), Some(0));
+        assert_last_event(CollectionCreated {
+            artist: BOB,
+            collection: 0,
+        });
+    });
+}
+
+#[test]
+fn test_create_artist_collection() {
+    new_test_ext(true).execute_with(|| {
+        assert_noop!(
+            ArtistsPallet::create_artist_collection(RuntimeOrigin::signed(BOB)),
+            Error::<Test>::NotAnArtist
+        );
+
+        assert_ok!(ArtistsPallet::create_artist_collection(
+            RuntimeOrigin::signed(ALICE)
+        ));
+        assert_eq!(ArtistsPallet::verified_collection_of(&ALICE), Some(0));
+
+        assert_noop!(
+            ArtistsPallet::create_artist_collection(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::CollectionAlreadyCreated
+        );
+
+        // The collection isn't verified while the artist is suspended
+        assert_ok!(ArtistsPallet::suspend_artist(
+            RuntimeOrigin::root(),
+            ALICE,
+            10,
+            SuspensionReason::CompromisedKey
+        ));
+        assert_eq!(ArtistsPallet::verified_collection_of(&ALICE), None);
+        assert_eq!(
+            ArtistsPallet::get_artist(ALICE).unwrap().collection,
+            Some(0)
+        );
+    });
+}
+
+/// Give `who` an identity displaying `display`, judged by the registrar 0
+fn judge_identity(who: AccountId, display: &[u8], judgement: Judgement<u64>) {
+    let registrar = 50;
+    if Identity::registrars().is_empty() {
+        assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), registrar));
+    }
+
+    let info = IdentityInfo {
+        additional: Default::default(),
+        display: Data::Raw(display.to_vec().try_into().unwrap()),
+        legal: Data::None,
+        web: Data::None,
+        riot: Data::None,
+        email: Data::None,
+        pgp_fingerprint: None,
+        image: Data::None,
+        twitter: Data::None,
+    };
+    let hash = <Test as frame_system::Config>::Hashing::hash_of(&info);
+    assert_ok!(Identity::set_identity(
+        RuntimeOrigin::signed(who),
+        Box::new(info)
+    ));
+    assert_ok!(Identity::provide_judgement(
+        RuntimeOrigin::signed(registrar),
+        0,
+        who,
+        judgement,
+        hash
+    ));
+}
+
+#[test]
+fn test_set_identity_policy() {
+    new_test_ext(true).execute_with(|| {
+        let policy = IdentityPolicy {
+            required_judgement: Some((0, IdentityJudgement::KnownGood)),
+            copy_display_name: true,
+        };
+        assert_noop!(
+            ArtistsPallet::set_identity_policy(RuntimeOrigin::signed(ALICE), policy.clone()),
+            BadOrigin
+        );
+
+        assert_eq!(ArtistsPallet::identity_policy(), IdentityPolicy::default());
+        assert_ok!(ArtistsPallet::set_identity_policy(
+            RuntimeOrigin::root(),
+            policy.clone()
+        ));
+        assert_eq!(ArtistsPallet::identity_policy(), policy);
+        assert_last_event(IdentityPolicySet { policy });
+    });
+}
+
+#[test]
+fn test_submit_candidacy_with_identity_policy() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::set_identity_policy(
+            RuntimeOrigin::root(),
+            IdentityPolicy {
+                required_judgement: Some((0, IdentityJudgement::KnownGood)),
+                copy_display_name: false,
+            }
+        ));
+
+        assert_noop!(
+            ArtistsPallet::submit_candidacy(RuntimeOrigin::signed(JOHN), b"Johnny".to_vec()),
+            Error::<Test>::IdentityJudgementRequired
+        );
+
+        // The judgement must be at least the required one
+        judge_identity(JOHN, b"John Doe", Judgement::Reasonable);
+        assert_noop!(
+            ArtistsPallet::submit_candidacy(RuntimeOrigin::signed(JOHN), b"Johnny".to_vec()),
+            Error::<Test>::IdentityJudgementRequired
+        );
+
+        judge_identity(JOHN, b"John Doe", Judgement::KnownGood);
+        assert_ok!(ArtistsPallet::submit_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            b"Johnny".to_vec()
+        ));
+        assert_eq!(
+            ArtistsPallet::get_candidate(JOHN).unwrap().name.to_vec(),
+            b"Johnny".to_vec()
+        );
+    });
+}
+
+#[test]
+fn test_submit_candidacy_copies_display_name() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::set_identity_policy(
+            RuntimeOrigin::root(),
+            IdentityPolicy {
+                required_judgement: None,
+                copy_display_name: true,
+            }
+        ));
+
+        // The given name is kept without identity
+        assert_ok!(ArtistsPallet::submit_candidacy(
+            RuntimeOrigin::signed(JOHN),
+            b"Johnny".to_vec()
+        ));
+        assert_eq!(
+            ArtistsPallet::get_candidate(JOHN).unwrap().name.to_vec(),
+            b"Johnny".to_vec()
+        );
+
+        let charlie = 4;
+        judge_identity(charlie, b"Charlie Parker", Judgement::Reasonable);
+        assert_ok!(ArtistsPallet::submit_candidacy(
+            RuntimeOrigin::signed(charlie),
+            b"Bird".to_vec()
+        ));
+        assert_last_event(CandidateAdded {
+            candidate: charlie,
+            name: b"Charlie Parker".to_vec().try_into().unwrap(),
+            deposit: CreationDepositAmount::get(),
+            created_at: System::block_number(),
+        });
+    });
+}
+
+#[test]
+fn test_approve_candidacy_with_identity_policy() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::set_identity_policy(
+            RuntimeOrigin::root(),
+            IdentityPolicy {
+                required_judgement: Some((0, IdentityJudgement::Reasonable)),
+                copy_display_name: false,
+            }
+        ));
+
+        // The candidacy was submitted before the policy
+        assert_noop!(
+            ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB),
+            Error::<Test>::IdentityJudgementRequired
+        );
+
+        judge_identity(BOB, b"Bob Marley", Judgement::KnownGood);
+        ApprovalDelay::set(10);
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+
+        // The judgement is withdrawn before the enactment
+        judge_identity(BOB, b"Bob Marley", Judgement::Erroneous);
+        System::set_block_number(11);
+        ArtistsPallet::on_initialize(11);
+
+        assert_last_event(ApprovalFailed {
+            candidate: BOB,
+            error: Error::<Test>::IdentityJudgementRequired.into(),
+        });
+        assert!(!ArtistsPallet::is_artist(&BOB));
+        assert!(ArtistsPallet::is_candidate(&BOB));
+    });
+}
+
+#[test]
+fn test_follow_artist() {
+    new_test_ext(true).execute_with(|| {
+        let deposit = FollowDeposit::get();
+        // Only the registered artists could be followed
+        assert_noop!(
+            ArtistsPallet::follow_artist(RuntimeOrigin::signed(JOHN), 1),
+            Error::<Test>::ArtistNotFound
+        );
+
+        assert_ok!(ArtistsPallet::follow_artist(RuntimeOrigin::signed(JOHN), 0));
+        assert_last_event(ArtistFollowed {
+            artist_id: 0,
+            follower: JOHN,
+        });
+        assert_eq!(ArtistsPallet::get_follow(0, JOHN), Some(deposit));
+        assert_eq!(Balances::reserved_balance(JOHN), deposit);
+        assert_noop!(
+            ArtistsPallet::follow_artist(RuntimeOrigin::signed(JOHN), 0),
+            Error::<Test>::AlreadyFollowing
+        );
+
+        assert_ok!(ArtistsPallet::follow_artist(RuntimeOrigin::signed(BOB), 0));
+        assert_eq!(ArtistsPallet::get_artist(ALICE).unwrap().followers, 2);
+
+        assert_ok!(ArtistsPallet::unfollow_artist(
+            RuntimeOrigin::signed(JOHN),
+            0
+        ));
+        assert_last_event(ArtistUnfollowed {
+            artist_id: 0,
+            follower: JOHN,
+        });
+        assert_eq!(ArtistsPallet::get_follow(0, JOHN), None);
+        assert_eq!(Balances::reserved_balance(JOHN), 0);
+        assert_eq!(ArtistsPallet::get_artist(ALICE).unwrap().followers, 1);
+        assert_noop!(
+            ArtistsPallet::unfollow_artist(RuntimeOrigin::signed(JOHN), 0),
+            Error::<Test>::NotFollowing
+        );
+    });
+}
+
+#[test]
+fn test_reap_followers_of_revoked_artist() {
+    new_test_ext(true).execute_with(|| {
+        let followers = [4, 5, 6, 7, 8];
+        for follower in followers {
+            assert_ok!(ArtistsPallet::follow_artist(
+                RuntimeOrigin::signed(follower),
+                0
+            ));
+        }
+        assert_noop!(
+            ArtistsPallet::reap_followers(RuntimeOrigin::signed(JOHN), 0),
+            Error::<Test>::ArtistStillRegistered
+        );
+
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            ALICE,
+            H256::repeat_byte(1)
+        ));
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            ALICE,
+            true
+        ));
+
+        // The revocation releases up to `MaxFollowersReaped` follows
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(FollowersReaped {
+            artist_id: 0,
+            count: 2,
+        }));
+        let remaining = || {
+            followers
+                .iter()
+                .filter(|follower| ArtistsPallet::get_follow(0, *follower).is_some())
+                .count()
+        };
+        assert_eq!(remaining(), 3);
+
+        // A follower could still leave by itself
+        let follower = *followers
+            .iter()
+            .find(|follower| ArtistsPallet::get_follow(0, *follower).is_some())
+            .unwrap();
+        assert_ok!(ArtistsPallet::unfollow_artist(
+            RuntimeOrigin::signed(follower),
+            0
+        ));
+        assert_eq!(Balances::reserved_balance(follower), 0);
+
+        assert_ok!(ArtistsPallet::reap_followers(
+            RuntimeOrigin::signed(JOHN),
+            0
+        ));
+        assert_last_event(FollowersReaped {
+            artist_id: 0,
+            count: 2,
+        });
+        assert_eq!(remaining(), 0);
+        for follower in followers {
+            assert_eq!(Balances::reserved_balance(follower), 0);
+        }
+        assert_noop!(
+            ArtistsPallet::reap_followers(RuntimeOrigin::signed(JOHN), 0),
+            Error::<Test>::NoFollowers
+        );
+    });
+}
+
+#[test]
+fn test_reputation_from_endorsements_and_age() {
+    new_test_ext(true).execute_with(|| {
+        assert_ok!(ArtistsPallet::endorse_candidate(
+            RuntimeOrigin::signed(ALICE),
+            BOB
+        ));
+        assert_ok!(ArtistsPallet::approve_candidacy(RuntimeOrigin::root(), BOB));
+
+        // The endorsements received as a candidate are accounted once approved
+        let artist = ArtistsPallet::get_artist(BOB).unwrap();
+        assert_eq!(artist.reputation_stats.endorsements, 1);
+        assert_eq!(ArtistsPallet::reputation_of(&BOB), Some(10));
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(ReputationChanged {
+            artist: BOB,
+            previous: 0,
+            reputation: 10,
+        }));
+
+        assert_noop!(
+            ArtistsPallet::refresh_reputation(RuntimeOrigin::signed(JOHN), JOHN),
+            Error::<Test>::ArtistNotFound
+        );
+
+        // The age is accounted once refreshed
+        System::set_block_number(251);
+        assert_eq!(ArtistsPallet::reputation_of(&BOB), Some(10));
+        assert_ok!(ArtistsPallet::refresh_reputation(
+            RuntimeOrigin::signed(JOHN),
+            BOB
+        ));
+        assert_last_event(ReputationChanged {
+            artist: BOB,
+            previous: 10,
+            reputation: 12,
+        });
+
+        // Nothing is emitted when the reputation stays the same
+        System::reset_events();
+        assert_ok!(ArtistsPallet::refresh_reputation(
+            RuntimeOrigin::signed(JOHN),
+            BOB
+        ));
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn test_reputation_from_activity_and_disputes() {
+    new_test_ext(true).execute_with(|| {
+        assert_eq!(ArtistsPallet::reputation_of(&ALICE), Some(0));
+        assert_eq!(ArtistsPallet::reputation_of(&BOB), None);
+
+        let artist_calls = || {
+            ArtistsPallet::get_artist(ALICE)
+                .unwrap()
+                .reputation_stats
+                .artist_calls
+        };
+
+        // The calls of other pallets aren't accounted
+        assert_ok!(ArtistsPallet::call_as_artist(
+            RuntimeOrigin::signed(ALICE),
+            Box::new(frame_system::Call::<Test>::remark { remark: vec![] }.into())
+        ));
+        assert_eq!(artist_calls(), 0);
+
+        assert_ok!(ArtistsPallet::call_as_artist(
+            RuntimeOrigin::signed(ALICE),
+            Box::new(
+                Call::<Test>::create_split {
+                    shares: shares(&[(ALICE, 100)])
+                }
+                .into()
+            )
+        ));
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(ReputationChanged {
+            artist: ALICE,
+            previous: 0,
+            reputation: 1,
+        }));
+
+        // Only the successful calls are accounted
+        assert_ok!(ArtistsPallet::call_as_artist(
+            RuntimeOrigin::signed(ALICE),
+            Box::new(Call::<Test>::lock_split { id: 1 }.into())
+        ));
+        assert_eq!(
+            ArtistsPallet::get_artist(ALICE)
+                .unwrap()
+                .reputation_stats
+                .artist_calls,
+            1
+        );
+
+        assert_ok!(ArtistsPallet::challenge_artist(
+            RuntimeOrigin::signed(JOHN),
+            ALICE,
+            H256::repeat_byte(1)
+        ));
+        assert_ok!(ArtistsPallet::resolve_dispute(
+            RuntimeOrigin::root(),
+            ALICE,
+            false
+        ));
+        System::assert_has_event(mock::RuntimeEvent::ArtistsPallet(ReputationChanged {
+            artist: ALICE,
+            previous: 1,
+            reputation: 6,
+        }));
+        assert_eq!(ArtistsPallet::reputation_of(&ALICE), Some(6));
+    });
+}
+
+#[test]
+fn test_free_candidacy_extension_is_transparent_in_metadata() {
+    let identifiers = |metadata: Vec<sp_runtime::traits::SignedExtensionMetadata>| {
+        metadata
+            .into_iter()
+            .map(|m| m.identifier)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        identifiers(FreeCandidacyExtension::metadata()),
+        identifiers(pallet_transaction_payment::ChargeTransactionPayment::<Test>::metadata())
+    );
+    assert_eq!(
+        FreeCandidacyExtension::IDENTIFIER,
+        pallet_transaction_payment::ChargeTransactionPayment::<Test>::IDENTIFIER
+    );
+}
+
+#[test]
+fn test_migrate_artists_to_v1() {
+    use crate::migrations::{v0, MigrateToV1};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext(false).execute_with(|| {
+        StorageVersion::new(0).put::<ArtistsPallet>();
+        System::set_block_number(10);
+
+        let name: BoundedVec<u8, NameMaxLength> = b"Old artist".to_vec().try_into().unwrap();
+        for (who, created_at) in [(JOHN, 4), (BOB, 2)] {
+            v0::Artists::<Test, ()>::insert(
+                who,
+                v0::OldArtist {
+                    name: name.clone(),
+                    created_at,
+                },
+            );
+        }
+        Candidates::<Test>::insert(
+            20,
+            CandidateData {
+                name: name.clone(),
+                created_at: 3,
+            },
+        );
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(ArtistsPallet::on_chain_storage_version(), 1);
+        assert_eq!(Artists::<Test>::count(), 2);
+        assert_eq!(ArtistsPallet::active_artists().to_vec(), vec![BOB, JOHN]);
+        assert_eq!(ArtistSet::get(), vec![BOB, JOHN]);
+        assert_eq!(ArtistsPallet::current_candidates().to_vec(), vec![20]);
+        assert_eq!(ArtistsPallet::next_artist_id(), 2);
+
+        for (who, created_at) in [(JOHN, 4), (BOB, 2)] {
+            let record = ArtistsPallet::get_artist(who).unwrap();
+            assert_eq!(record.name, name);
+            assert_eq!(record.created_at, created_at);
+            assert_eq!(record.level, VerificationLevel::default());
+            assert_eq!(record.deposit, CreationDepositAmount::get());
+            assert!(!record.deposit_exempt);
+            assert_eq!(record.status, ArtistStatus::Active);
+            assert_eq!(ArtistsPallet::get_artist_account(record.id), Some(who));
+        }
+
+        // Running it again is a no-op
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Artists::<Test>::count(), 2);
+        assert_eq!(ArtistsPallet::next_artist_id(), 2);
+    });
+}
+
+#[test]
+fn test_endorsement_threshold_with_failing_approval() {
+    new_test_ext(true).execute_with(|| {
+        EndorsementThreshold::set(Some(1));
+        // Fill the registry up to `MaxArtists`
+        for artist in 4..8 {
+            assert_ok!(ArtistsPallet::submit_candidacy(
+                RuntimeOrigin::signed(artist),
+                b"Artist".to_vec()
+            ));
+            assert_ok!(ArtistsPallet::approve_candidacy(
+                RuntimeOrigin::root(),
+                artist
+            ));
+        }
+
+        // The endorsements are recorded even if the approval fails
+        assert_ok!(ArtistsPallet::endorse_candidate(
+            RuntimeOrigin::signed(ALICE),
+            BOB
+        ));
+        assert_last_event(ApprovalFailed {
+            candidate: BOB,
+            error: Error::<Test>::TooManyArtists.into(),
+        });
+        assert_ok!(ArtistsPallet::endorse_candidate(
+            RuntimeOrigin::signed(4),
+            BOB
+        ));
+        assert_eq!(
+            ArtistsPallet::get_endorsements(BOB).to_vec(),
+            vec![ALICE, 4]
+        );
+        assert!(ArtistsPallet::is_candidate(&BOB));
+        assert!(!ArtistsPallet::is_artist(&BOB));
+    });
+}
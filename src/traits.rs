@@ -0,0 +1,218 @@
+//! Traits exposing the artists registry to other pallets.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::{Member, Parameter},
+    traits::{
+        fungibles,
+        tokens::{nonfungibles_v2, Balance},
+        Get,
+    },
+};
+use pallet_identity::{Data, Judgement};
+use sp_runtime::traits::{AtLeast32BitUnsigned, SaturatedConversion};
+
+/// Resolve the royalty splits defined by the artists, for a royalty engine to
+/// distribute the revenues of their works.
+pub trait RoyaltySplitProvider<AccountId> {
+    /// The payees of the active version of a split with their shares, summing to 100%.
+    fn active_split(id: SplitId) -> Option<Vec<(AccountId, Perbill)>>;
+
+    /// The payees of a given version of a split with their shares, summing to 100%.
+    /// Previous versions stay resolvable once replaced, until the split is retired.
+    fn split_at(id: SplitId, version: SplitVersion) -> Option<Vec<(AccountId, Perbill)>>;
+}
+
+/// Issue the fan tokens of the artists.
+pub trait ArtistTokenFactory<AccountId> {
+    /// The identifier of a token.
+    type AssetId: Member + Parameter + MaxEncodedLen;
+
+    /// The balance of a token.
+    type Balance: Balance;
+
+    /// Create a token owned by the given artist with the given metadata, minting the
+    /// supply to the artist.
+    fn create_token(
+        asset: Self::AssetId,
+        artist: &AccountId,
+        name: Vec<u8>,
+        symbol: Vec<u8>,
+        decimals: u8,
+        supply: Self::Balance,
+    ) -> DispatchResult;
+}
+
+/// No token is ever issued.
+impl<AccountId> ArtistTokenFactory<AccountId> for () {
+    type AssetId = ();
+    type Balance = u128;
+
+    fn create_token(
+        _asset: (),
+        _artist: &AccountId,
+        _name: Vec<u8>,
+        _symbol: Vec<u8>,
+        _decimals: u8,
+        _supply: u128,
+    ) -> DispatchResult {
+        Err(DispatchError::Unavailable)
+    }
+}
+
+/// Issue the tokens in a `fungibles` registry, e.g. `pallet_assets`, with the artist as
+/// owner and a minimum balance of one.
+pub struct FungibleTokens<Assets>(PhantomData<Assets>);
+
+impl<AccountId, Assets> ArtistTokenFactory<AccountId> for FungibleTokens<Assets>
+where
+    AccountId: Clone,
+    Assets: fungibles::Create<AccountId>
+        + fungibles::Mutate<AccountId>
+        + fungibles::metadata::Mutate<AccountId>,
+{
+    type AssetId = Assets::AssetId;
+    type Balance = Assets::Balance;
+
+    fn create_token(
+        asset: Self::AssetId,
+        artist: &AccountId,
+        name: Vec<u8>,
+        symbol: Vec<u8>,
+        decimals: u8,
+        supply: Self::Balance,
+    ) -> DispatchResult {
+        Assets::create(asset.clone(), artist.clone(), false, One::one())?;
+        Assets::set(asset.clone(), artist, name, symbol, decimals)?;
+        Assets::mint_into(asset, artist, supply)?;
+        Ok(())
+    }
+}
+
+/// Create the verified NFT collection of an artist.
+pub trait ArtistCollectionFactory<AccountId> {
+    /// The identifier of a collection.
+    type CollectionId: Member + Parameter + MaxEncodedLen;
+
+    /// Create a collection owned by the given artist.
+    fn create_collection(artist: &AccountId) -> Result<Self::CollectionId, DispatchError>;
+}
+
+/// No collection is ever created.
+impl<AccountId> ArtistCollectionFactory<AccountId> for () {
+    type CollectionId = ();
+
+    fn create_collection(_artist: &AccountId) -> Result<(), DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+}
+
+/// Create the collections in a `nonfungibles_v2` registry, e.g. `pallet_nfts`, with the
+/// artist as owner and admin and the settings given by `CollectionConfig`.
+pub struct NftCollections<Nfts, CollectionConfig, Config>(
+    PhantomData<(Nfts, CollectionConfig, Config)>,
+);
+
+impl<AccountId, Nfts, CollectionConfig, Config> ArtistCollectionFactory<AccountId>
+    for NftCollections<Nfts, CollectionConfig, Config>
+where
+    Nfts: nonfungibles_v2::Create<AccountId, Config>,
+    Nfts::CollectionId: Member + Parameter + MaxEncodedLen,
+    CollectionConfig: Get<Config>,
+{
+    type CollectionId = Nfts::CollectionId;
+
+    fn create_collection(artist: &AccountId) -> Result<Self::CollectionId, DispatchError> {
+        Nfts::create_collection(artist, artist, &CollectionConfig::get())
+    }
+}
+
+/// Provide the on-chain identities of the accounts along with their judgements.
+pub trait IdentityProvider<AccountId> {
+    /// The positive judgement given to the identity of `who` by the registrar, if any.
+    fn judgement(who: &AccountId, registrar: RegistrarIndex) -> Option<IdentityJudgement>;
+
+    /// The raw display name of the identity of `who`, if any.
+    fn display_name(who: &AccountId) -> Option<Vec<u8>>;
+}
+
+/// No account has an identity.
+impl<AccountId> IdentityProvider<AccountId> for () {
+    fn judgement(_who: &AccountId, _registrar: RegistrarIndex) -> Option<IdentityJudgement> {
+        None
+    }
+
+    fn display_name(_who: &AccountId) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+impl<T: pallet_identity::Config> IdentityProvider<T::AccountId> for pallet_identity::Pallet<T> {
+    fn judgement(who: &T::AccountId, registrar: RegistrarIndex) -> Option<IdentityJudgement> {
+        let registration = Self::identity(who)?;
+        registration
+            .judgements
+            .iter()
+            .find(|(index, _)| *index == registrar)
+            .and_then(|(_, judgement)| match judgement {
+                Judgement::KnownGood => Some(IdentityJudgement::KnownGood),
+                Judgement::Reasonable => Some(IdentityJudgement::Reasonable),
+                _ => None,
+            })
+    }
+
+    fn display_name(who: &T::AccountId) -> Option<Vec<u8>> {
+        match Self::identity(who)?.info.display {
+            Data::Raw(name) => Some(name.into_inner()),
+            _ => None,
+        }
+    }
+}
+
+/// Score the reputation of an artist from its stats and its age, the number of blocks
+/// since its registration.
+pub trait ReputationPolicy<BlockNumber> {
+    /// The reputation of an artist with the given stats and age.
+    fn reputation(stats: &ReputationStats, age: BlockNumber) -> Reputation;
+}
+
+/// Every artist has no reputation.
+impl<BlockNumber> ReputationPolicy<BlockNumber> for () {
+    fn reputation(_stats: &ReputationStats, _age: BlockNumber) -> Reputation {
+        0
+    }
+}
+
+/// Sum the points earned by an artist: `EndorsementPoints` per endorsement,
+/// `DisputePoints` per dismissed dispute, `CallPoints` per call dispatched as artist and
+/// a point per `AgePeriod` blocks since its registration.
+pub struct LinearReputation<EndorsementPoints, DisputePoints, CallPoints, AgePeriod>(
+    PhantomData<(EndorsementPoints, DisputePoints, CallPoints, AgePeriod)>,
+);
+
+impl<BlockNumber, EndorsementPoints, DisputePoints, CallPoints, AgePeriod>
+    ReputationPolicy<BlockNumber>
+    for LinearReputation<EndorsementPoints, DisputePoints, CallPoints, AgePeriod>
+where
+    BlockNumber: AtLeast32BitUnsigned,
+    EndorsementPoints: Get<Reputation>,
+    DisputePoints: Get<Reputation>,
+    CallPoints: Get<Reputation>,
+    AgePeriod: Get<BlockNumber>,
+{
+    fn reputation(stats: &ReputationStats, age: BlockNumber) -> Reputation {
+        let period = AgePeriod::get().max(One::one());
+        let age_points: Reputation = (age / period).saturated_into();
+
+        stats
+            .endorsements
+            .saturating_mul(EndorsementPoints::get())
+            .saturating_add(
+                stats
+                    .dismissed_disputes
+                    .saturating_mul(DisputePoints::get()),
+            )
+            .saturating_add(stats.artist_calls.saturating_mul(CallPoints::get()))
+            .saturating_add(age_points)
+    }
+}
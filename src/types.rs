@@ -1,10 +1,414 @@
 use super::*;
-use allfeat_support::types::actors::artist::{ArtistData, CandidateData};
+use allfeat_support::types::actors::artist::CandidateData;
 use frame_system::pallet_prelude::BlockNumberFor;
+use serde::{Deserialize, Serialize};
 
-pub type BalanceOf<T> =
-    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type BalanceOf<T, I = ()> =
+    <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-pub type ArtistOf<T> = ArtistData<BoundedVec<u8, <T as Config>::NameMaxLength>, BlockNumberFor<T>>;
-pub type CandidateOf<T> =
-    CandidateData<BoundedVec<u8, <T as Config>::NameMaxLength>, BlockNumberFor<T>>;
+pub type NegativeImbalanceOf<T, I = ()> = <<T as Config<I>>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+pub type AssetIdOf<T, I = ()> = <<T as Config<I>>::Assets as ArtistTokenFactory<
+    <T as frame_system::Config>::AccountId,
+>>::AssetId;
+pub type AssetBalanceOf<T, I = ()> = <<T as Config<I>>::Assets as ArtistTokenFactory<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
+pub type CollectionIdOf<T, I = ()> = <<T as Config<I>>::Collections as ArtistCollectionFactory<
+    <T as frame_system::Config>::AccountId,
+>>::CollectionId;
+
+pub type ArtistOf<T, I = ()> = ArtistRecord<
+    BoundedVec<u8, <T as Config<I>>::NameMaxLength>,
+    BoundedVec<u8, <T as Config<I>>::MetadataMaxLength>,
+    BlockNumberFor<T>,
+    BalanceOf<T, I>,
+    AssetIdOf<T, I>,
+    CollectionIdOf<T, I>,
+>;
+pub type ProfileOf<T, I = ()> = ArtistProfile<
+    BoundedVec<u8, <T as Config<I>>::NameMaxLength>,
+    BoundedVec<u8, <T as Config<I>>::MetadataMaxLength>,
+>;
+pub type CollaborationOf<T, I = ()> = Collaboration<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::Hash,
+    BoundedVec<u8, <T as Config<I>>::MetadataMaxLength>,
+    BalanceOf<T, I>,
+    BlockNumberFor<T>,
+>;
+pub type WorkOf<T, I = ()> = Work<
+    BoundedVec<u8, <T as Config<I>>::TitleMaxLength>,
+    <T as frame_system::Config>::Hash,
+    BlockNumberFor<T>,
+>;
+pub type RoyaltySplitOf<T, I = ()> =
+    RoyaltySplit<<T as frame_system::Config>::AccountId, BalanceOf<T, I>>;
+pub type SharesOf<T, I = ()> =
+    BoundedVec<(<T as frame_system::Config>::AccountId, Perbill), <T as Config<I>>::MaxSplitPayees>;
+pub type CandidateOf<T, I = ()> =
+    CandidateData<BoundedVec<u8, <T as Config<I>>::NameMaxLength>, BlockNumberFor<T>>;
+pub type DisputeOf<T, I = ()> = Dispute<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::Hash,
+    BalanceOf<T, I>,
+    BlockNumberFor<T>,
+>;
+pub type EvidenceOf<T, I = ()> = Evidence<
+    <T as frame_system::Config>::Hash,
+    BoundedVec<u8, <T as Config<I>>::EvidenceMaxLength>,
+>;
+
+/// The verdict of a verifier on a candidacy.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Verdict {
+    /// The verifier vouches for the candidate identity.
+    Approved,
+    /// The verifier could not confirm the candidate identity.
+    Rejected,
+}
+
+/// The stable identifier of an artist, kept for its whole lifetime and never reused.
+pub type ArtistId = u32;
+
+/// The index of a fan token, in the order the tokens were issued.
+pub type FanTokenIndex = u32;
+
+/// The verification tier of an artist, each tier granting more privileges.
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub enum VerificationLevel {
+    /// A newly approved artist.
+    #[default]
+    Emerging,
+    /// An artist whose identity was thoroughly verified.
+    Verified,
+    /// An artist certified by a label.
+    CertifiedLabel,
+}
+
+/// The on-chain record of a certified artist.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ArtistRecord<Name, Metadata, BlockNumber, Balance, AssetId, CollectionId> {
+    /// The stable identifier of the artist.
+    pub id: ArtistId,
+    /// The name of the artist.
+    pub name: Name,
+    /// Free-form metadata about the artist, e.g. an off-chain profile reference.
+    pub metadata: Metadata,
+    /// The block the artist was approved at.
+    pub created_at: BlockNumber,
+    /// The verification tier of the artist.
+    pub level: VerificationLevel,
+    /// The total amount held for the artist, creation and level deposits included.
+    pub deposit: Balance,
+    /// Whether the artist was registered without holding any deposit, in which case
+    /// `deposit` stays zero whatever the verification level.
+    pub deposit_exempt: bool,
+    /// The fan token issued by the artist with the deposit held for it, if any.
+    pub fan_token: Option<(AssetId, Balance)>,
+    /// The verified NFT collection of the artist, if any.
+    pub collection: Option<CollectionId>,
+    /// The number of accounts following the artist.
+    pub followers: u32,
+    /// The reputation of the artist given by `T::ReputationPolicy`.
+    pub reputation: Reputation,
+    /// The inputs of the reputation of the artist, besides its age.
+    pub reputation_stats: ReputationStats,
+    /// Whether the artist is active or temporarily suspended.
+    pub status: ArtistStatus<BlockNumber>,
+}
+
+impl<Name, Metadata, BlockNumber, Balance, AssetId, CollectionId>
+    ArtistRecord<Name, Metadata, BlockNumber, Balance, AssetId, CollectionId>
+{
+    /// Whether the artist is currently suspended.
+    pub fn is_suspended(&self) -> bool {
+        matches!(self.status, ArtistStatus::Suspended { .. })
+    }
+}
+
+/// The public profile of an artist registered by the admin.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ArtistProfile<Name, Metadata> {
+    /// The name of the artist.
+    pub name: Name,
+    /// Free-form metadata about the artist.
+    pub metadata: Metadata,
+}
+
+/// The status of an artist.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ArtistStatus<BlockNumber> {
+    /// The artist could act as such.
+    #[default]
+    Active,
+    /// The artist is treated as inactive until the given block.
+    Suspended {
+        until: BlockNumber,
+        reason: SuspensionReason,
+    },
+}
+
+/// What led to the approval of a candidate.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ApprovalSource {
+    /// The candidacy was approved by `AdminOrigin`.
+    Admin,
+    /// The candidacy reached the attestation quorum of the verifiers.
+    Attestations,
+    /// The candidacy reached the endorsement threshold of the artists.
+    Endorsements,
+}
+
+/// Why an artist got suspended.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SuspensionReason {
+    /// The works of the artist are subject to a copyright dispute.
+    CopyrightDispute,
+    /// The artist account is suspected to be compromised.
+    CompromisedKey,
+    /// Any other reason, detailed off-chain.
+    Other,
+}
+
+/// An evidence attached to a candidacy to support its review.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Evidence<Hash, Data> {
+    /// The content hash of a document stored off-chain.
+    Document(Hash),
+    /// A link to a profile of the candidate, e.g. on a streaming platform.
+    Link(Data),
+    /// A statement signed by the candidate through the submitting transaction.
+    Statement(Data),
+}
+
+/// A challenge opened against an artist, awaiting its resolution.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Dispute<AccountId, Hash, Balance, BlockNumber> {
+    /// The account which challenged the artist.
+    pub challenger: AccountId,
+    /// The content hash of the evidence backing the challenge.
+    pub evidence_hash: Hash,
+    /// The bond held from the challenger.
+    pub bond: Balance,
+    /// The block the challenge was opened at.
+    pub opened_at: BlockNumber,
+}
+
+/// An International Standard Recording Code, e.g. `USRC17607839`.
+pub type Isrc = [u8; 12];
+
+/// An International Standard Musical Work Code without separators, e.g. `T0345246801`.
+pub type Iswc = [u8; 11];
+
+/// A work registered in the catalog of an artist.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Work<Title, Hash, BlockNumber> {
+    /// The title of the work.
+    pub title: Title,
+    /// The code identifying the recording, unique across all the catalogs.
+    pub isrc: Isrc,
+    /// The code identifying the underlying composition, if any.
+    pub iswc: Option<Iswc>,
+    /// The content hash of the work.
+    pub content_hash: Hash,
+    /// The block the work is released at.
+    pub released_at: BlockNumber,
+}
+
+impl<Title, Hash, BlockNumber> Work<Title, Hash, BlockNumber> {
+    /// Whether the ISRC and ISWC of the work are well-formed, regardless of their
+    /// registration with the agencies.
+    pub fn has_valid_codes(&self) -> bool {
+        let isrc_valid = self
+            .isrc
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+        let iswc_valid = self.iswc.map_or(true, |iswc| {
+            iswc[0] == b'T' && iswc[1..].iter().all(u8::is_ascii_digit)
+        });
+        isrc_valid && iswc_valid
+    }
+}
+
+/// The identifier of a royalty split.
+pub type SplitId = u32;
+
+/// The version of a royalty split, starting at zero and incremented on each update.
+pub type SplitVersion = u32;
+
+/// A royalty split defined by an artist, whose shares are stored by version.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltySplit<AccountId, Balance> {
+    /// The artist which defined the split.
+    pub owner: AccountId,
+    /// The active version of the split.
+    pub version: SplitVersion,
+    /// The total deposit held for the versions of the split.
+    pub deposit: Balance,
+    /// Whether the active version is final.
+    pub locked: bool,
+}
+
+/// The identifier of a collaboration between two artists.
+pub type CollaborationId = u32;
+
+/// A collaboration between two artists on a work.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Collaboration<AccountId, Hash, Metadata, Balance, BlockNumber> {
+    /// The artist which proposed the collaboration.
+    pub proposer: AccountId,
+    /// The artist invited to collaborate.
+    pub counterparty: AccountId,
+    /// The content hash of the work.
+    pub work_hash: Hash,
+    /// Free-form metadata about the collaboration, e.g. the credited roles.
+    pub metadata: Metadata,
+    /// The deposit held from the proposer until the collaboration is confirmed.
+    pub deposit: Balance,
+    /// Whether the counterparty confirmed the collaboration.
+    pub status: CollaborationStatus<BlockNumber>,
+}
+
+/// The status of a collaboration.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CollaborationStatus<BlockNumber> {
+    /// The collaboration waits for the counterparty since the given block.
+    Proposed { at: BlockNumber },
+    /// Both artists confirmed the collaboration at the given block.
+    Confirmed { at: BlockNumber },
+}
+
+/// The reputation score of an artist.
+pub type Reputation = u32;
+
+/// What an artist did to build its reputation.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ReputationStats {
+    /// The endorsements received by the artist as a candidate.
+    pub endorsements: u32,
+    /// The challenges against the artist that were dismissed.
+    pub dismissed_disputes: u32,
+    /// The calls of this pallet successfully dispatched through `call_as_artist`.
+    pub artist_calls: u32,
+}
+
+/// The index of an identity registrar.
+pub type RegistrarIndex = u32;
+
+/// The positive judgements a registrar can give to an identity, by increasing confidence.
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum IdentityJudgement {
+    /// The identity was checked and looks reasonable.
+    Reasonable,
+    /// The identity is known to be good.
+    KnownGood,
+}
+
+/// The identity requirements of the candidacies.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct IdentityPolicy {
+    /// The registrar whose judgement is required with the minimum judgement, if any.
+    pub required_judgement: Option<(RegistrarIndex, IdentityJudgement)>,
+    /// Whether the display name of the identity replaces the name of the candidates.
+    pub copy_display_name: bool,
+}
+
+/// The calls dispatched by a candidate through `call_as_candidate` during an era.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CallUsage<BlockNumber> {
+    /// The block the era started at.
+    pub era_start: BlockNumber,
+    /// The number of calls dispatched during the era.
+    pub calls: u32,
+    /// The weight of the calls dispatched during the era.
+    pub weight: Weight,
+}
+
+/// What a candidate could still dispatch through `call_as_candidate` until the
+/// end of the current era.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CallBudget<BlockNumber> {
+    /// The number of calls left.
+    pub calls: u32,
+    /// The weight left.
+    pub weight: Weight,
+    /// The block the budget is reset at.
+    pub resets_at: BlockNumber,
+}
+
+/// An artist registered at genesis.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenesisArtist<AccountId> {
+    /// The account of the artist.
+    pub account: AccountId,
+    /// The name of the artist.
+    pub name: Vec<u8>,
+    /// Free-form metadata about the artist.
+    #[serde(default)]
+    pub metadata: Vec<u8>,
+    /// The verification tier of the artist.
+    #[serde(default)]
+    pub level: VerificationLevel,
+    /// Whether the artist is registered without holding any deposit, e.g. for
+    /// legacy artists imported from another registry.
+    #[serde(default)]
+    pub deposit_exempt: bool,
+    /// The identifier of the artist, the next available one if `None`.
+    #[serde(default)]
+    pub id: Option<ArtistId>,
+}
+
+/// Why a genesis config of the pallet is invalid.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum GenesisError<AccountId> {
+    /// The name of the account is longer than `NameMaxLength`.
+    NameTooLong(AccountId),
+    /// The metadata of the artist is longer than `MetadataMaxLength`.
+    MetadataTooLong(AccountId),
+    /// The account is listed more than once as an artist.
+    DuplicateArtist(AccountId),
+    /// The account is listed more than once as a candidate.
+    DuplicateCandidate(AccountId),
+    /// The account is listed both as an artist and as a candidate.
+    ArtistAndCandidate(AccountId),
+    /// The identifier is given to more than one artist.
+    DuplicateArtistId(ArtistId),
+    /// The free balance of the account can't cover its deposit.
+    InsufficientBalance(AccountId),
+    /// There are more artists than `MaxArtists`.
+    TooManyArtists,
+    /// There are more candidates than `MaxCandidates`.
+    TooManyCandidates,
+}
@@ -1,27 +1,10 @@
-
-//! Autogenerated weights for `pallet_artists`
+//! Weights for `pallet_artists`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-09-20, STEPS: `50`, REPEAT: 30, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `DESKTOP-11NSJM8`, CPU: `Intel(R) Core(TM) i7-10700K CPU @ 3.80GHz`
-//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// target/release/allfeat
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_artists
-// --extrinsic
-// *
-// --steps
-// 50
-// --repeat
-// 30
-// --output
-// weights.rs
+//! PLACEHOLDER ESTIMATES, NOT GENERATED BY THE BENCHMARK CLI.
+//! Each weight is estimated from the storage accesses of the call and the maximum encoded
+//! length of the accessed items. Replace this file with the output of
+//! `benchmark pallet --pallet pallet_artists --extrinsic '*' --steps 50 --repeat 20`
+//! run on the reference hardware before relying on these weights.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -33,38 +16,978 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_artists.
 pub trait WeightInfo {
-	fn submit_candidacy(_n: u32, ) -> Weight;
+	fn submit_candidacy(n: u32, ) -> Weight;
 	fn withdraw_candidacy() -> Weight;
 	fn approve_candidacy(n: u32, ) -> Weight;
 	fn call_as_artist() -> Weight;
 	fn call_as_candidate() -> Weight;
+	fn add_verifier() -> Weight;
+	fn remove_verifier() -> Weight;
+	fn set_verifier_fee() -> Weight;
+	fn request_attestation() -> Weight;
+	fn cancel_attestation_request() -> Weight;
+	fn attest_candidacy(v: u32, ) -> Weight;
+	fn set_verification_level() -> Weight;
+	fn add_candidacy_evidence() -> Weight;
+	fn reject_candidacy() -> Weight;
+	fn challenge_artist() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn close_expired_dispute() -> Weight;
+	fn endorse_candidate(e: u32, ) -> Weight;
+	fn suspend_artist() -> Weight;
+	fn unsuspend_artist() -> Weight;
+	fn lift_suspensions(s: u32, ) -> Weight;
+	fn veto_approval() -> Weight;
+	fn enact_approvals(a: u32, ) -> Weight;
+	fn sponsor_candidacy(n: u32, ) -> Weight;
+	fn take_over_deposit() -> Weight;
+	fn force_register_artist() -> Weight;
+	fn propose_collaboration() -> Weight;
+	fn accept_collaboration() -> Weight;
+	fn cancel_collaboration() -> Weight;
+	fn register_work() -> Weight;
+	fn update_work() -> Weight;
+	fn retire_work() -> Weight;
+	fn create_split(p: u32, ) -> Weight;
+	fn update_split(p: u32, ) -> Weight;
+	fn lock_split() -> Weight;
+	fn create_artist_token() -> Weight;
+	fn create_artist_collection() -> Weight;
+	fn set_identity_policy() -> Weight;
+	fn follow_artist() -> Weight;
+	fn unfollow_artist() -> Weight;
+	fn reap_followers(n: u32, ) -> Weight;
+	fn refresh_reputation() -> Weight;
+	fn dissolve_collaboration() -> Weight;
+	fn retire_split() -> Weight;
 }
 
-impl WeightInfo for () {
+/// Placeholder weights for pallet_artists, estimated for the Substrate node and recommended
+/// hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Artists CandidateIdentityPolicy (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
 	// Storage: Artists Artists (r:1 w:0)
 	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
 	/// The range of component `n` is `[1, 128]`.
-	fn submit_candidacy(_n: u32, ) -> Weight {
-		Weight::default()
+	fn submit_candidacy(n: u32, ) -> Weight {
+		Weight::from_parts(35_920_000, 3593)
+			.saturating_add(Weight::from_parts(2_310, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists AttestationRequests (r:1 w:0)
+	// Storage: Artists Attestations (r:0 w:1)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: Artists Endorsements (r:0 w:1)
+	// Storage: Artists CandidateCallUsage (r:0 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn withdraw_candidacy() -> Weight {
-		Weight::default()
-
+		Weight::from_parts(52_212_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
+	// Storage: Artists CandidateIdentityPolicy (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
 	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Nfts NextCollectionId (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	// Storage: Nfts CollectionAccount (r:0 w:1)
 	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists NextArtistId (r:1 w:1)
+	// Storage: Artists ArtistAccounts (r:0 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:0)
+	// Storage: Artists AttestationRequests (r:1 w:0)
+	// Storage: Artists Attestations (r:0 w:1)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: Artists Endorsements (r:1 w:1)
+	// Storage: Artists CandidateCallUsage (r:0 w:1)
+	// Storage: Artists CounterForArtists (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	/// The range of component `n` is `[1, 128]`.
-	fn approve_candidacy(_n: u32, ) -> Weight {
-		Weight::default()
-
+	fn approve_candidacy(n: u32, ) -> Weight {
+		Weight::from_parts(88_702_000, 3593)
+			.saturating_add(Weight::from_parts(3_140, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	fn call_as_artist() -> Weight {
+		Weight::from_parts(14_930_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists CandidateCallUsage (r:1 w:1)
+	fn call_as_candidate() -> Weight {
+		Weight::from_parts(17_284_000, 3523)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists CounterForVerifiers (r:1 w:1)
+	// Storage: Artists Verifiers (r:1 w:1)
+	fn add_verifier() -> Weight {
+		Weight::from_parts(19_655_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists CounterForVerifiers (r:1 w:1)
+	// Storage: Artists Verifiers (r:1 w:1)
+	fn remove_verifier() -> Weight {
+		Weight::from_parts(20_971_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Verifiers (r:1 w:1)
+	fn set_verifier_fee() -> Weight {
+		Weight::from_parts(15_208_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists Verifiers (r:1 w:0)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: Artists Attestations (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn request_attestation() -> Weight {
+		Weight::from_parts(37_560_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_attestation_request() -> Weight {
+		Weight::from_parts(31_047_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Verifiers (r:1 w:0)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Artists Attestations (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists ApprovalQueue (r:1 w:1)
+	/// The range of component `v` is `[1, 16]`.
+	fn attest_candidacy(v: u32, ) -> Weight {
+		Weight::from_parts(56_893_000, 6196)
+			.saturating_add(Weight::from_parts(4_870_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(v.into()))
+	}
+	// Storage: Artists Verifiers (r:1 w:0)
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_verification_level() -> Weight {
+		Weight::from_parts(33_716_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn add_candidacy_evidence() -> Weight {
+		Weight::from_parts(30_582_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: Artists Attestations (r:0 w:1)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: Artists Endorsements (r:0 w:1)
+	// Storage: Artists CandidateCallUsage (r:0 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_candidacy() -> Weight {
+		Weight::from_parts(65_375_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Disputes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn challenge_artist() -> Weight {
+		Weight::from_parts(32_104_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Disputes (r:1 w:1)
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists ArtistAccounts (r:0 w:1)
+	// Storage: Artists Catalog (r:1 w:1)
+	// Storage: Artists WorksByIsrc (r:0 w:16)
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists SuspensionExpiries (r:1 w:1)
+	// Storage: Artists CounterForArtists (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(77_931_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(27_u64))
+	}
+	// Storage: Artists Disputes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn close_expired_dispute() -> Weight {
+		Weight::from_parts(29_433_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Artists (r:2 w:1)
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists Endorsements (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists ApprovalQueue (r:1 w:1)
+	/// The range of component `e` is `[0, 16]`.
+	fn endorse_candidate(e: u32, ) -> Weight {
+		Weight::from_parts(45_016_000, 6082)
+			.saturating_add(Weight::from_parts(1_152_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(e.into()))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists SuspensionExpiries (r:2 w:2)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	fn suspend_artist() -> Weight {
+		Weight::from_parts(31_406_000, 4629)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists SuspensionExpiries (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	fn unsuspend_artist() -> Weight {
+		Weight::from_parts(28_254_000, 4629)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists SuspensionExpiries (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	/// The range of component `s` is `[0, 16]`.
+	fn lift_suspensions(s: u32, ) -> Weight {
+		Weight::from_parts(9_870_000, 3205)
+			.saturating_add(Weight::from_parts(8_416_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2541).saturating_mul(s.into()))
+	}
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists ApprovalQueue (r:1 w:1)
+	fn veto_approval() -> Weight {
+		Weight::from_parts(21_340_000, 3557)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists ApprovalQueue (r:1 w:1)
+	// Storage: Artists CounterForArtists (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	/// The range of component `a` is `[0, 16]`.
+	fn enact_approvals(a: u32, ) -> Weight {
+		Weight::from_parts(8_904_000, 1698)
+			.saturating_add(Weight::from_parts(43_726_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((12_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(a.into()))
+	}
+	// Storage: Artists CandidateIdentityPolicy (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists Sponsors (r:0 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 128]`.
+	fn sponsor_candidacy(n: u32, ) -> Weight {
+		Weight::from_parts(37_205_000, 3593)
+			.saturating_add(Weight::from_parts(2_290, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn take_over_deposit() -> Weight {
+		Weight::from_parts(36_988_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Artists CounterForArtists (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: Artists Attestations (r:0 w:1)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: Artists Endorsements (r:0 w:1)
+	// Storage: Artists CandidateCallUsage (r:0 w:1)
+	// Storage: Artists NextArtistId (r:1 w:1)
+	// Storage: Artists ArtistAccounts (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn force_register_artist() -> Weight {
+		Weight::from_parts(78_225_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	// Storage: Artists Artists (r:2 w:0)
+	// Storage: Artists NextCollaborationId (r:1 w:1)
+	// Storage: Artists PendingCollaborations (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists Collaborations (r:0 w:1)
+	fn propose_collaboration() -> Weight {
+		Weight::from_parts(41_903_000, 6082)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Artists Artists (r:2 w:0)
+	// Storage: Artists Collaborations (r:1 w:1)
+	// Storage: Artists ArtistCollaborations (r:2 w:2)
+	// Storage: Artists PendingCollaborations (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn accept_collaboration() -> Weight {
+		Weight::from_parts(49_318_000, 6082)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: Artists Collaborations (r:1 w:1)
+	// Storage: Artists PendingCollaborations (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_collaboration() -> Weight {
+		Weight::from_parts(36_407_000, 6082)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists WorksByIsrc (r:1 w:1)
+	// Storage: Artists Catalog (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_work() -> Weight {
+		Weight::from_parts(35_482_000, 7611)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Catalog (r:1 w:1)
+	fn update_work() -> Weight {
+		Weight::from_parts(24_108_000, 7611)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Catalog (r:1 w:1)
+	// Storage: Artists WorksByIsrc (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn retire_work() -> Weight {
+		Weight::from_parts(33_960_000, 7611)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists NextSplitId (r:1 w:1)
+	// Storage: Artists ArtistSplits (r:1 w:1)
+	// Storage: Artists RoyaltySplits (r:0 w:1)
+	// Storage: Artists SplitVersions (r:0 w:1)
+	/// The range of component `p` is `[1, 16]`.
+	fn create_split(p: u32, ) -> Weight {
+		Weight::from_parts(30_517_000, 3593)
+			.saturating_add(Weight::from_parts(612_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists RoyaltySplits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists SplitVersions (r:0 w:1)
+	/// The range of component `p` is `[1, 16]`.
+	fn update_split(p: u32, ) -> Weight {
+		Weight::from_parts(31_244_000, 3593)
+			.saturating_add(Weight::from_parts(640_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists RoyaltySplits (r:1 w:1)
+	fn lock_split() -> Weight {
+		Weight::from_parts(19_862_000, 3530)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists NextFanToken (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn create_artist_token() -> Weight {
+		Weight::from_parts(66_904_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Nfts NextCollectionId (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	// Storage: Nfts CollectionAccount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn create_artist_collection() -> Weight {
+		Weight::from_parts(41_276_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: Artists CandidateIdentityPolicy (r:0 w:1)
+	fn set_identity_policy() -> Weight {
+		Weight::from_parts(9_874_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists ArtistAccounts (r:1 w:0)
+	// Storage: Artists Followers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists Artists (r:1 w:1)
+	fn follow_artist() -> Weight {
+		Weight::from_parts(34_862_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Followers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists ArtistAccounts (r:1 w:0)
+	// Storage: Artists Artists (r:1 w:1)
+	fn unfollow_artist() -> Weight {
+		Weight::from_parts(33_517_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists ArtistAccounts (r:1 w:0)
+	// Storage: Artists Followers (r:1 w:0)
+	/// The range of component `n` is `[0, 16]`.
+	fn reap_followers(n: u32, ) -> Weight {
+		Weight::from_parts(8_964_000, 3493)
+			.saturating_add(Weight::from_parts(18_736_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(0_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	fn refresh_reputation() -> Weight {
+		Weight::from_parts(15_386_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Collaborations (r:1 w:1)
+	// Storage: Artists ArtistCollaborations (r:2 w:2)
+	fn dissolve_collaboration() -> Weight {
+		Weight::from_parts(24_730_000, 4082)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists RoyaltySplits (r:1 w:1)
+	// Storage: Artists ArtistSplits (r:1 w:1)
+	// Storage: Artists SplitVersions (r:0 w:3)
+	// Storage: System Account (r:1 w:1)
+	fn retire_split() -> Weight {
+		Weight::from_parts(38_615_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// Placeholder estimates, for backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Artists CandidateIdentityPolicy (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 128]`.
+	fn submit_candidacy(n: u32, ) -> Weight {
+		Weight::from_parts(35_920_000, 3593)
+			.saturating_add(Weight::from_parts(2_310, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists AttestationRequests (r:1 w:0)
+	// Storage: Artists Attestations (r:0 w:1)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: Artists Endorsements (r:0 w:1)
+	// Storage: Artists CandidateCallUsage (r:0 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_candidacy() -> Weight {
+		Weight::from_parts(52_212_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	// Storage: Artists CandidateIdentityPolicy (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Nfts NextCollectionId (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	// Storage: Nfts CollectionAccount (r:0 w:1)
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists NextArtistId (r:1 w:1)
+	// Storage: Artists ArtistAccounts (r:0 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:0)
+	// Storage: Artists AttestationRequests (r:1 w:0)
+	// Storage: Artists Attestations (r:0 w:1)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: Artists Endorsements (r:1 w:1)
+	// Storage: Artists CandidateCallUsage (r:0 w:1)
+	// Storage: Artists CounterForArtists (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 128]`.
+	fn approve_candidacy(n: u32, ) -> Weight {
+		Weight::from_parts(88_702_000, 3593)
+			.saturating_add(Weight::from_parts(3_140, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	// Storage: Artists Artists (r:1 w:1)
 	fn call_as_artist() -> Weight {
-		Weight::default()
+		Weight::from_parts(14_930_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists CandidateCallUsage (r:1 w:1)
 	fn call_as_candidate() -> Weight {
-		Weight::default()
+		Weight::from_parts(17_284_000, 3523)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists CounterForVerifiers (r:1 w:1)
+	// Storage: Artists Verifiers (r:1 w:1)
+	fn add_verifier() -> Weight {
+		Weight::from_parts(19_655_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists CounterForVerifiers (r:1 w:1)
+	// Storage: Artists Verifiers (r:1 w:1)
+	fn remove_verifier() -> Weight {
+		Weight::from_parts(20_971_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Verifiers (r:1 w:1)
+	fn set_verifier_fee() -> Weight {
+		Weight::from_parts(15_208_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists Verifiers (r:1 w:0)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: Artists Attestations (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn request_attestation() -> Weight {
+		Weight::from_parts(37_560_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_attestation_request() -> Weight {
+		Weight::from_parts(31_047_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Verifiers (r:1 w:0)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Artists Attestations (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists ApprovalQueue (r:1 w:1)
+	/// The range of component `v` is `[1, 16]`.
+	fn attest_candidacy(v: u32, ) -> Weight {
+		Weight::from_parts(56_893_000, 6196)
+			.saturating_add(Weight::from_parts(4_870_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(v.into()))
+	}
+	// Storage: Artists Verifiers (r:1 w:0)
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_verification_level() -> Weight {
+		Weight::from_parts(33_716_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn add_candidacy_evidence() -> Weight {
+		Weight::from_parts(30_582_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: Artists Attestations (r:0 w:1)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: Artists Endorsements (r:0 w:1)
+	// Storage: Artists CandidateCallUsage (r:0 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_candidacy() -> Weight {
+		Weight::from_parts(65_375_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Disputes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn challenge_artist() -> Weight {
+		Weight::from_parts(32_104_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Disputes (r:1 w:1)
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists ArtistAccounts (r:0 w:1)
+	// Storage: Artists Catalog (r:1 w:1)
+	// Storage: Artists WorksByIsrc (r:0 w:16)
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists SuspensionExpiries (r:1 w:1)
+	// Storage: Artists CounterForArtists (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(77_931_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(27_u64))
+	}
+	// Storage: Artists Disputes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn close_expired_dispute() -> Weight {
+		Weight::from_parts(29_433_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists Artists (r:2 w:1)
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists Endorsements (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists ApprovalQueue (r:1 w:1)
+	/// The range of component `e` is `[0, 16]`.
+	fn endorse_candidate(e: u32, ) -> Weight {
+		Weight::from_parts(45_016_000, 6082)
+			.saturating_add(Weight::from_parts(1_152_000, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(e.into()))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists SuspensionExpiries (r:2 w:2)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	fn suspend_artist() -> Weight {
+		Weight::from_parts(31_406_000, 4629)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists SuspensionExpiries (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	fn unsuspend_artist() -> Weight {
+		Weight::from_parts(28_254_000, 4629)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists SuspensionExpiries (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	/// The range of component `s` is `[0, 16]`.
+	fn lift_suspensions(s: u32, ) -> Weight {
+		Weight::from_parts(9_870_000, 3205)
+			.saturating_add(Weight::from_parts(8_416_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2541).saturating_mul(s.into()))
+	}
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists ApprovalQueue (r:1 w:1)
+	fn veto_approval() -> Weight {
+		Weight::from_parts(21_340_000, 3557)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Artists ApprovalQueue (r:1 w:1)
+	// Storage: Artists CounterForArtists (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	/// The range of component `a` is `[0, 16]`.
+	fn enact_approvals(a: u32, ) -> Weight {
+		Weight::from_parts(8_904_000, 1698)
+			.saturating_add(Weight::from_parts(43_726_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(a.into()))
+	}
+	// Storage: Artists CandidateIdentityPolicy (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists Sponsors (r:0 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 128]`.
+	fn sponsor_candidacy(n: u32, ) -> Weight {
+		Weight::from_parts(37_205_000, 3593)
+			.saturating_add(Weight::from_parts(2_290, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists Candidates (r:1 w:0)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn take_over_deposit() -> Weight {
+		Weight::from_parts(36_988_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Artists CounterForArtists (r:1 w:1)
+	// Storage: Artists SortedArtists (r:1 w:1)
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists Candidates (r:1 w:1)
+	// Storage: Artists SortedCandidates (r:1 w:1)
+	// Storage: Artists Sponsors (r:1 w:1)
+	// Storage: Artists CandidacyDeposits (r:1 w:1)
+	// Storage: Artists PendingApprovals (r:1 w:1)
+	// Storage: Artists AttestationRequests (r:1 w:1)
+	// Storage: Artists Attestations (r:0 w:1)
+	// Storage: Artists CandidacyEvidence (r:1 w:1)
+	// Storage: Artists Endorsements (r:0 w:1)
+	// Storage: Artists CandidateCallUsage (r:0 w:1)
+	// Storage: Artists NextArtistId (r:1 w:1)
+	// Storage: Artists ArtistAccounts (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn force_register_artist() -> Weight {
+		Weight::from_parts(78_225_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	// Storage: Artists Artists (r:2 w:0)
+	// Storage: Artists NextCollaborationId (r:1 w:1)
+	// Storage: Artists PendingCollaborations (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists Collaborations (r:0 w:1)
+	fn propose_collaboration() -> Weight {
+		Weight::from_parts(41_903_000, 6082)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: Artists Artists (r:2 w:0)
+	// Storage: Artists Collaborations (r:1 w:1)
+	// Storage: Artists ArtistCollaborations (r:2 w:2)
+	// Storage: Artists PendingCollaborations (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn accept_collaboration() -> Weight {
+		Weight::from_parts(49_318_000, 6082)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: Artists Collaborations (r:1 w:1)
+	// Storage: Artists PendingCollaborations (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_collaboration() -> Weight {
+		Weight::from_parts(36_407_000, 6082)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists WorksByIsrc (r:1 w:1)
+	// Storage: Artists Catalog (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_work() -> Weight {
+		Weight::from_parts(35_482_000, 7611)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Catalog (r:1 w:1)
+	fn update_work() -> Weight {
+		Weight::from_parts(24_108_000, 7611)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists Catalog (r:1 w:1)
+	// Storage: Artists WorksByIsrc (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn retire_work() -> Weight {
+		Weight::from_parts(33_960_000, 7611)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists NextSplitId (r:1 w:1)
+	// Storage: Artists ArtistSplits (r:1 w:1)
+	// Storage: Artists RoyaltySplits (r:0 w:1)
+	// Storage: Artists SplitVersions (r:0 w:1)
+	/// The range of component `p` is `[1, 16]`.
+	fn create_split(p: u32, ) -> Weight {
+		Weight::from_parts(30_517_000, 3593)
+			.saturating_add(Weight::from_parts(612_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists RoyaltySplits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists SplitVersions (r:0 w:1)
+	/// The range of component `p` is `[1, 16]`.
+	fn update_split(p: u32, ) -> Weight {
+		Weight::from_parts(31_244_000, 3593)
+			.saturating_add(Weight::from_parts(640_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists RoyaltySplits (r:1 w:1)
+	fn lock_split() -> Weight {
+		Weight::from_parts(19_862_000, 3530)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Artists NextFanToken (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn create_artist_token() -> Weight {
+		Weight::from_parts(66_904_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	// Storage: Nfts NextCollectionId (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	// Storage: Nfts CollectionAccount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn create_artist_collection() -> Weight {
+		Weight::from_parts(41_276_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: Artists CandidateIdentityPolicy (r:0 w:1)
+	fn set_identity_policy() -> Weight {
+		Weight::from_parts(9_874_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists ArtistAccounts (r:1 w:0)
+	// Storage: Artists Followers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists Artists (r:1 w:1)
+	fn follow_artist() -> Weight {
+		Weight::from_parts(34_862_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Followers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Artists ArtistAccounts (r:1 w:0)
+	// Storage: Artists Artists (r:1 w:1)
+	fn unfollow_artist() -> Weight {
+		Weight::from_parts(33_517_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists ArtistAccounts (r:1 w:0)
+	// Storage: Artists Followers (r:1 w:0)
+	/// The range of component `n` is `[0, 16]`.
+	fn reap_followers(n: u32, ) -> Weight {
+		Weight::from_parts(8_964_000, 3493)
+			.saturating_add(Weight::from_parts(18_736_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(0_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	// Storage: Artists Artists (r:1 w:1)
+	fn refresh_reputation() -> Weight {
+		Weight::from_parts(15_386_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Artists Collaborations (r:1 w:1)
+	// Storage: Artists ArtistCollaborations (r:2 w:2)
+	fn dissolve_collaboration() -> Weight {
+		Weight::from_parts(24_730_000, 4082)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Artists Artists (r:1 w:0)
+	// Storage: Artists RoyaltySplits (r:1 w:1)
+	// Storage: Artists ArtistSplits (r:1 w:1)
+	// Storage: Artists SplitVersions (r:0 w:3)
+	// Storage: System Account (r:1 w:1)
+	fn retire_split() -> Weight {
+		Weight::from_parts(38_615_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 }